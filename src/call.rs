@@ -1,10 +1,20 @@
-use std::{borrow::Cow, path::{Path, PathBuf}};
+use std::{borrow::Cow, path::PathBuf, time::Duration};
 
-use crate::{error::{ArgError, Error, ExpectedArg, InvalidArg, InvalidFlag}, flag::{Flag, FlagKind, FlagOption}, help::Help, round::{Round, Rounds}, run::Run};
+use crate::{command::{AddRound, Aggregate, Command, CommandKind, Season}, error::{ArgError, Error, ExpectedArg, InvalidArg, InvalidFlag}, flag::{Flag, FlagKind, FlagOption}, help::Help, output::{Format, Notation, Output}, record::{Source, STDIN}, reveal::Reveal, round::{Count, Round, RoundId, Rounds, Weights}, run::Run, scoring::{Ranking, Scoring, TieBreak}};
 
 enum FlagCall
 {
-    File
+    File,
+    Output,
+    Delay,
+    Aggregate,
+    Best,
+    TieBreak,
+    Ranking,
+    Weights,
+    Count,
+    Decimals,
+    Since
 }
 
 impl FlagCall
@@ -22,7 +32,8 @@ impl FlagCall
             {
                 Err(InvalidFlag::HelpAfterInteger.into())
             },
-            FlagKind::File => if let Cow::Owned(_) = &call.file //TODO: use .is_owned() when stabilized
+            // A command writes to the file, so it can't have more than one
+            FlagKind::File => if call.command.is_some_and(CommandKind::writes) && !call.files.is_empty()
             {
                 Err(InvalidArg::InvalidFlag {
                     error: InvalidFlag::FileAlreadySpecified
@@ -32,15 +43,106 @@ impl FlagCall
             {
                 Ok(Err(Self::File))
             },
-            FlagKind::Rev => Ok(Ok(Flag::Rev))
+            FlagKind::Rev => Ok(Ok(Flag::Rev)),
+            FlagKind::Table => Ok(Ok(Flag::Table)),
+            FlagKind::Output => Ok(Err(Self::Output)),
+            FlagKind::Long => Ok(Ok(Flag::Long)),
+            FlagKind::Strict => Ok(Ok(Flag::Strict)),
+            FlagKind::Watch => Ok(Ok(Flag::Watch)),
+            FlagKind::Reveal => Ok(Ok(Flag::Reveal)),
+            FlagKind::Delay => Ok(Err(Self::Delay)),
+            FlagKind::Podium => Ok(Ok(Flag::Podium)),
+            FlagKind::New => Ok(Ok(Flag::New)),
+            FlagKind::Aggregate => Ok(Err(Self::Aggregate)),
+            FlagKind::Best => Ok(Err(Self::Best)),
+            FlagKind::TieBreak => Ok(Err(Self::TieBreak)),
+            FlagKind::Ranking => Ok(Err(Self::Ranking)),
+            FlagKind::Weights => Ok(Err(Self::Weights)),
+            FlagKind::Count => Ok(Err(Self::Count)),
+            FlagKind::Decimals => Ok(Err(Self::Decimals)),
+            FlagKind::Fractions => Ok(Ok(Flag::Fractions)),
+            FlagKind::Since => Ok(Err(Self::Since)),
+            FlagKind::Chart => Ok(Ok(Flag::Chart))
         }
     }
 
-    fn parse(self, _no: usize, arg: String) -> Result<Result<Flag, Self>, Error>
+    fn parse(self, arg: String) -> Result<Result<Flag, Self>, InvalidArg>
     {
         match self
         {
-            FlagCall::File => Ok(Ok(Flag::File(PathBuf::from(arg))))
+            FlagCall::File => Ok(Ok(Flag::File(PathBuf::from(arg)))),
+            FlagCall::Output => match Format::from_name(&arg)
+            {
+                Some(format) => Ok(Ok(Flag::Output(format))),
+                None => Err(InvalidArg::NonexistentFormat {
+                    format: arg.into_boxed_str()
+                })
+            },
+            FlagCall::Delay => match arg.trim().parse().ok().and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+            {
+                Some(delay) => Ok(Ok(Flag::Delay(delay))),
+                None => Err(InvalidArg::InvalidDelay {
+                    delay: arg.into_boxed_str()
+                })
+            },
+            FlagCall::Aggregate => match Aggregate::from_name(&arg)
+            {
+                Some(aggregate) => Ok(Ok(Flag::Aggregate(aggregate))),
+                None => Err(InvalidArg::NonexistentAggregate {
+                    aggregate: arg.into_boxed_str()
+                })
+            },
+            FlagCall::Best => match arg.trim().parse()
+            {
+                Ok(best) if best > 0 => Ok(Ok(Flag::Best(best))),
+                _ => Err(InvalidArg::InvalidBest {
+                    best: arg.into_boxed_str()
+                })
+            },
+            FlagCall::TieBreak => match TieBreak::parse_chain(&arg)
+            {
+                Ok(tie_breaks) => Ok(Ok(Flag::TieBreak(tie_breaks))),
+                Err(tie_break) => Err(InvalidArg::NonexistentTieBreak {
+                    tie_break
+                })
+            },
+            FlagCall::Ranking => match Ranking::from_name(&arg)
+            {
+                Some(ranking) => Ok(Ok(Flag::Ranking(ranking))),
+                None => Err(InvalidArg::NonexistentRanking {
+                    ranking: arg.into_boxed_str()
+                })
+            },
+            FlagCall::Weights => match Weights::parse(&arg)
+            {
+                Ok(weights) => Ok(Ok(Flag::Weights(weights))),
+                Err(weight) => Err(InvalidArg::InvalidWeight {
+                    weight
+                })
+            },
+            FlagCall::Count => match Count::from_name(&arg)
+            {
+                Some(count) => Ok(Ok(Flag::Count(count))),
+                None => Err(InvalidArg::InvalidCount {
+                    count: arg.into_boxed_str()
+                })
+            },
+            FlagCall::Decimals => match arg.trim().parse()
+            {
+                Ok(decimals) if decimals <= Notation::MAX_DECIMALS => Ok(Ok(Flag::Decimals(decimals))),
+                _ => Err(InvalidArg::InvalidDecimals {
+                    decimals: arg.into_boxed_str()
+                })
+            },
+            // A single round means the standings after it
+            FlagCall::Since => match Call::parse_round(&arg)?
+            {
+                Round::One(round) => Ok(Ok(Flag::Since(Round::Range {
+                    start: None,
+                    end: Some(round)
+                }))),
+                round => Ok(Ok(Flag::Since(round)))
+            }
         }
     }
 
@@ -48,7 +150,17 @@ impl FlagCall
     {
         match self
         {
-            FlagCall::File => Err(ExpectedArg::Filename)
+            FlagCall::File => Err(ExpectedArg::Filename),
+            FlagCall::Output => Err(ExpectedArg::Format),
+            FlagCall::Delay => Err(ExpectedArg::Delay),
+            FlagCall::Aggregate => Err(ExpectedArg::Aggregate),
+            FlagCall::Best => Err(ExpectedArg::Best),
+            FlagCall::TieBreak => Err(ExpectedArg::TieBreak),
+            FlagCall::Ranking => Err(ExpectedArg::Ranking),
+            FlagCall::Weights => Err(ExpectedArg::Weights),
+            FlagCall::Count => Err(ExpectedArg::Count),
+            FlagCall::Decimals => Err(ExpectedArg::Decimals),
+            FlagCall::Since => Err(ExpectedArg::Since)
         }
     }
 
@@ -71,9 +183,22 @@ pub struct Call
     flag_call: Option<FlagCall>,
     flags: Vec<FlagKind>,
     rounds: Rounds,
-    file: Cow<'static, Path>,
+    files: Vec<PathBuf>,
     help: Option<Help>,
-    rev: bool,
+    output: Output,
+    strict: bool,
+    watch: bool,
+    reveal: Option<Reveal>,
+    command: Option<CommandKind>,
+    entries: Vec<Box<str>>,
+    new: bool,
+    aggregate: Aggregate,
+    best: Option<usize>,
+    tie_breaks: Option<Box<[TieBreak]>>,
+    ranking: Ranking,
+    weights: Option<Weights>,
+    count: Count,
+    since: Option<Rounds>,
     no: usize
 }
 
@@ -86,9 +211,22 @@ impl Call
             flag_call: None,
             flags: Vec::new(),
             rounds: Rounds::All,
-            file: Cow::Borrowed(crate::default_file_path()),
+            files: Vec::new(),
             help: None,
-            rev: false,
+            output: Output::new(),
+            strict: false,
+            watch: false,
+            reveal: None,
+            command: None,
+            entries: Vec::new(),
+            new: false,
+            aggregate: Aggregate::Total,
+            best: None,
+            tie_breaks: None,
+            ranking: Ranking::Standard,
+            weights: None,
+            count: Count::All,
+            since: None,
             no: 0
         }
     }
@@ -118,6 +256,38 @@ impl Call
         })
     }
 
+    /// Whether the poengsum-file is read from standard input, so that nothing else can use it.
+    fn reads_stdin(&self) -> bool
+    {
+        self.files.iter().any(|file| file.as_os_str() == STDIN)
+    }
+
+    /// The reveal goes from the last place to the first, so it's shown in reverse.
+    fn reveal(&mut self) -> &mut Reveal
+    {
+        if self.reveal.is_none()
+        {
+            self.output.rev ^= true;
+        }
+        self.reveal.get_or_insert_with(Reveal::new)
+    }
+
+    /// Adds a file to read, from "--file" or as a night of the season.
+    fn add_file(&mut self, file: PathBuf) -> Result<(), InvalidFlag>
+    {
+        if file.as_os_str() == STDIN && (self.watch || self.command.is_some_and(CommandKind::writes))
+        {
+            return Err(InvalidFlag::StdinInUse)
+        }
+        // Standard input can only be read once
+        if (self.command.is_some_and(CommandKind::writes) && !self.files.is_empty()) || (file.as_os_str() == STDIN && self.reads_stdin())
+        {
+            return Err(InvalidFlag::FileAlreadySpecified)
+        }
+        self.files.push(file);
+        Ok(())
+    }
+
     fn add_flag(&mut self, flag: Flag) -> Result<(), InvalidFlag>
     {
         let kind = flag.kind();
@@ -132,17 +302,105 @@ impl Call
                 }
                 Ok(())
             }
-            Flag::File(file) => if let Cow::Borrowed(_) = &self.file
-            {
-                self.file = Cow::Owned(file);
+            Flag::File(file) => self.add_file(file),
+            Flag::Rev => {
+                self.output.rev ^= true;
                 Ok(())
-            }
-            else
-            {
-                Err(InvalidFlag::FileAlreadySpecified)
             },
-            Flag::Rev => {
-                self.rev ^= true;
+            Flag::Table => {
+                self.output.table = true;
+                Ok(())
+            },
+            Flag::Output(format) => {
+                self.output.format = format;
+                Ok(())
+            },
+            Flag::Long => {
+                self.output.long = true;
+                Ok(())
+            },
+            Flag::Strict => {
+                self.strict = true;
+                Ok(())
+            },
+            Flag::Watch => {
+                if self.reads_stdin()
+                {
+                    return Err(InvalidFlag::StdinInUse)
+                }
+                if self.reveal.is_some()
+                {
+                    return Err(InvalidFlag::RevealWhileWatching)
+                }
+                self.watch = true;
+                Ok(())
+            },
+            Flag::Reveal => {
+                if self.watch
+                {
+                    return Err(InvalidFlag::RevealWhileWatching)
+                }
+                self.reveal();
+                Ok(())
+            },
+            Flag::Delay(delay) => {
+                if self.watch
+                {
+                    return Err(InvalidFlag::RevealWhileWatching)
+                }
+                self.reveal().delay = Some(delay);
+                Ok(())
+            },
+            Flag::Podium => {
+                if self.watch
+                {
+                    return Err(InvalidFlag::RevealWhileWatching)
+                }
+                self.reveal().podium = true;
+                Ok(())
+            },
+            Flag::New => {
+                self.new = true;
+                Ok(())
+            },
+            Flag::Aggregate(aggregate) => {
+                self.aggregate = aggregate;
+                Ok(())
+            },
+            Flag::Best(best) => {
+                self.best = Some(best);
+                Ok(())
+            },
+            Flag::TieBreak(tie_breaks) => {
+                self.tie_breaks = Some(tie_breaks);
+                Ok(())
+            },
+            Flag::Ranking(ranking) => {
+                self.ranking = ranking;
+                Ok(())
+            },
+            Flag::Weights(weights) => {
+                self.weights = Some(weights);
+                Ok(())
+            },
+            Flag::Count(count) => {
+                self.count = count;
+                Ok(())
+            },
+            Flag::Decimals(decimals) => {
+                self.output.notation = Notation::Decimals(decimals);
+                Ok(())
+            },
+            Flag::Fractions => {
+                self.output.notation = Notation::Fractions;
+                Ok(())
+            },
+            Flag::Since(round) => {
+                self.since.get_or_insert(Rounds::All).add_round(round);
+                Ok(())
+            },
+            Flag::Chart => {
+                self.output.chart = true;
                 Ok(())
             }
         };
@@ -175,19 +433,29 @@ impl Call
         let parse_int_notrim = |s: &str| s.parse::<usize>()
             .map_err(InvalidArg::from);
 
-        let parse_int = |s: &str| offs(parse_int_notrim(s.trim())?);
+        // Anything that doesn't look like a number is the name of a round
+        let is_name = |s: &str| !s.starts_with(|c: char| c.is_ascii_digit() || c == '+');
+
+        let parse_id_notrim = |s: &str| if is_name(s)
+        {
+            Ok(RoundId::Name(s.into()))
+        }
+        else
+        {
+            offs(parse_int_notrim(s)?).map(RoundId::No)
+        };
 
         let mut iter = if arg.contains("..")
         {
             arg.split_terminator("..")
         }
-        else if arg.contains(".")
+        else if arg.contains(".") && !is_name(arg.trim())
         {
             return Err(InvalidArg::UseTwoDots)
         }
         else
         {
-            let round = parse_int(arg)?;
+            let round = parse_id_notrim(arg.trim())?;
             return Ok(Round::One(round))
         };
 
@@ -196,7 +464,7 @@ impl Call
             match s.is_empty()
             {
                 true => Ok(None),
-                false => offs(parse_int_notrim(s)?).map(Some)
+                false => parse_id_notrim(s).map(Some)
             }
         };
 
@@ -211,8 +479,8 @@ impl Call
             None => None
         };
 
-        let check = |start: Option<usize>, end: Option<usize>| {
-            if let (Some(start), Some(end)) = (start, end) && start > end
+        let check = |start: &Option<RoundId>, end: &Option<RoundId>| {
+            if let (Some(start), Some(end)) = (start.as_ref().and_then(RoundId::no), end.as_ref().and_then(RoundId::no)) && start > end
             {
                 return Err(InvalidArg::NotInOrder {
                     start: start + 1,
@@ -223,12 +491,12 @@ impl Call
             Ok(())
         };
 
-        check(start, end)?;
+        check(&start, &end)?;
         for s in iter
         {
             let start = end;
             end = parse_bound(s)?;
-            check(start, end)?;
+            check(&start, &end)?;
         }
 
         Ok(Round::Range {
@@ -254,16 +522,23 @@ impl Call
 
             if let Some(flag_call) = self.flag_call.take()
             {
-                match flag_call.parse(self.no, arg.clone() /* :( */)?
+                match try_of!(Help::catch(flag_call.parse(arg.clone() /* :( */).map(Some), None, self.help.as_mut()))
                 {
-                    Ok(flag) => try_of!(self.add_flag(flag)),
-                    Err(flag_call) => self.flag_call = Some(flag_call),
+                    Some(Ok(flag)) => try_of!(self.add_flag(flag)),
+                    Some(Err(flag_call)) => self.flag_call = Some(flag_call),
+                    None => ()
                 };
                 continue
             }
 
             fn parse_trimmed(call: &mut Call, exe: &'static str, arg: &str) -> Result<(), ArgError>
             {
+                // A night of the season can be read from standard input, like with "--file"
+                if arg == STDIN && call.command == Some(CommandKind::Season) && call.help.is_none()
+                {
+                    call.add_file(PathBuf::from(arg))?;
+                    return Ok(())
+                }
                 if let Some(flags) = arg.trim().strip_prefix("-")
                 {
                     match flags.strip_prefix("-")
@@ -281,6 +556,31 @@ impl Call
                 {
                     return Err(InvalidArg::IntegerAfterHelp.into())
                 }
+                else if let Some(command) = call.command
+                {
+                    match command
+                    {
+                        CommandKind::AddRound => call.entries.push(arg.into()),
+                        // Each night of the season is its own file
+                        CommandKind::Season => call.add_file(PathBuf::from(arg))?,
+                        CommandKind::Enter => return Err(InvalidArg::ArgAfterCommand {
+                            command
+                        }.into())
+                    }
+                }
+                // The command has to come before any rounds
+                else if let Rounds::All = call.rounds && let Some(command) = CommandKind::from_name(arg)
+                {
+                    if command.writes() && call.reads_stdin()
+                    {
+                        return Err(InvalidFlag::StdinInUse.into())
+                    }
+                    if command.writes() && call.files.len() > 1
+                    {
+                        return Err(InvalidFlag::FileAlreadySpecified.into())
+                    }
+                    call.command = Some(command);
+                }
                 else
                 {
                     call.rounds.add_round(Call::parse_round(arg)?);
@@ -304,7 +604,7 @@ impl Call
             self.add_flag(flag).map_err(|e| e.at(exe, self.no, None))?;
         }
 
-        let Self { exe: _, flag_call, mut flags, rounds, file, help, rev, no } = self;
+        let Self { exe: _, flag_call, mut flags, rounds, files, help, output, strict, watch, reveal, command, entries, new, aggregate, best, tie_breaks, ranking, weights, count, since, no } = self;
         let _ = (flag_call, no);
 
         if let Some(mut help) = help
@@ -316,6 +616,33 @@ impl Call
             })
         }
 
-        Run::new(rounds, file, rev)
+        let command = command.map(|command| match command
+        {
+            CommandKind::Enter => Command::Enter,
+            CommandKind::AddRound => Command::AddRound(AddRound::new(exe, entries, new)),
+            CommandKind::Season => Command::Season(Season::new(aggregate, best))
+        });
+
+        let sources = if files.is_empty()
+        {
+            vec![Source::File(Cow::Borrowed(crate::default_file_path()))]
+        }
+        else
+        {
+            files.into_iter()
+                .map(|file| Source::from(Cow::Owned(file)))
+                .collect()
+        };
+
+        let scoring = Scoring {
+            rounds,
+            count,
+            tie_breaks,
+            ranking,
+            weights,
+            since
+        };
+
+        Run::new(scoring, sources.into_boxed_slice(), output, strict, watch, reveal, command)
     }
 }
\ No newline at end of file
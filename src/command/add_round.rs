@@ -0,0 +1,293 @@
+use std::io::{BufRead, Write};
+
+use crate::{error::{Error, InvalidEntry, InvalidIO, InvalidRead}, record::{RecordParser, RecordWriter, Source}, run::Run, style, terminal};
+
+/// Separates the team and points in the arguments, like `"Kate's Bush"=7.5`.
+const ARG_SEPARATOR: char = '=';
+/// Separates the team and points in the input, like in the poengsum-file.
+const LINE_SEPARATOR: char = ':';
+
+/// A team name and its points, as they were given.
+type TeamPoints = (Box<str>, Box<str>);
+
+/// How sure we are that a name given to the command is a team in the poengsum-file.
+enum Match
+{
+    Sure(usize),
+    Unsure(usize),
+    Unknown
+}
+
+/// Adds a whole round at once, from the arguments or from `team: points` pairs in the input.
+pub struct AddRound
+{
+    exe: &'static str,
+    entries: Vec<Box<str>>,
+    new: bool
+}
+
+impl AddRound
+{
+    pub fn new(exe: &'static str, entries: Vec<Box<str>>, new: bool) -> Self
+    {
+        Self {
+            exe,
+            entries,
+            new
+        }
+    }
+
+    /// Case and whitespace are ignored when comparing names.
+    fn simplify(team: &str) -> String
+    {
+        team.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    /// How many characters must be added, removed or changed to get from one name to the other.
+    fn distance(a: &str, b: &str) -> usize
+    {
+        let b = b.chars().collect::<Vec<_>>();
+        let mut row = (0..=b.len()).collect::<Vec<_>>();
+        for (i, a) in a.chars().enumerate()
+        {
+            let mut diagonal = row[0];
+            row[0] = i + 1;
+            for (j, &b) in b.iter().enumerate()
+            {
+                let above = row[j + 1];
+                row[j + 1] = (diagonal + usize::from(a != b))
+                    .min(above + 1)
+                    .min(row[j] + 1);
+                diagonal = above;
+            }
+        }
+        row[b.len()]
+    }
+
+    fn find(teams: &[&str], team: &str) -> Match
+    {
+        if let Some(i) = teams.iter().position(|other| *other == team)
+        {
+            return Match::Sure(i)
+        }
+        let simple = Self::simplify(team);
+        if let Some(i) = teams.iter().position(|other| Self::simplify(other) == simple)
+        {
+            return Match::Sure(i)
+        }
+
+        // Small typos and shortened names are probably meant as one of the teams, but it's better to ask
+        let max_distance = (simple.chars().count() / 4).max(1);
+        let closest = teams.iter()
+            .map(|other| Self::simplify(other))
+            .enumerate()
+            .filter(|(_, other)| other.starts_with(&simple) || Self::distance(other, &simple) <= max_distance)
+            .min_by_key(|(_, other)| Self::distance(other, &simple));
+        match closest
+        {
+            Some((i, _)) => Match::Unsure(i),
+            None => Match::Unknown
+        }
+    }
+
+    /// Splits an entry into the team and the points, checking that the points can be written to the poengsum-file.
+    fn parse_entry(entry: &str, separator: char) -> Result<TeamPoints, InvalidEntry>
+    {
+        let Some((team, points)) = entry.rsplit_once(separator)
+        else
+        {
+            return Err(InvalidEntry::MissingSeparator {
+                entry: entry.into(),
+                separator
+            })
+        };
+
+        let team = team.trim();
+        if team.is_empty()
+        {
+            return Err(InvalidEntry::UnnamedTeam {
+                entry: entry.into()
+            })
+        }
+        // The points are one cell in the poengsum-file, so "7 + 2" is written as "7+2"
+        let points = points.split_whitespace()
+            .collect::<String>();
+
+        RecordParser::parse_points(&points)
+            .map_err(|(col, error)| InvalidEntry::CannotParsePoints {
+                team: team.into(),
+                points: points.as_str().into(),
+                col,
+                error
+            })?;
+
+        Ok((team.into(), points.into_boxed_str()))
+    }
+
+    fn read_entries(&self, input: &mut impl BufRead) -> Result<Vec<TeamPoints>, Error>
+    {
+        let mut entries = Vec::new();
+        for (i, line) in input.lines().enumerate()
+        {
+            let line = line.map_err(|io_error| InvalidRead::InvalidIO {
+                    io_error,
+                    error: InvalidIO::Read {
+                        row: i + 1
+                    }
+                }.at(Source::Stdin))?;
+            let content = line[..RecordParser::content_end(&line)].trim();
+            if content.is_empty()
+            {
+                continue
+            }
+            entries.push(Self::parse_entry(content, LINE_SEPARATOR).map_err(|error| error.at(self.exe))?);
+        }
+        Ok(entries)
+    }
+
+    /// Asks if the team was meant as the other one, counting the rows of the input that are answered.
+    fn confirm(team: &str, other: &str, input: &mut impl BufRead, output: &mut impl Write, row: &mut usize) -> Result<bool, Error>
+    {
+        terminal::show(output, format_args!("{prompt} ", prompt = style::info(format!("Did you mean \"{other}\" for \"{team}\"? [y/N]"))));
+
+        // No more input means no
+        *row += 1;
+        let line = terminal::read_line(input, *row)?.unwrap_or_default();
+        let answer = line.trim();
+        Ok(answer.eq_ignore_ascii_case("y") || answer.eq_ignore_ascii_case("yes"))
+    }
+
+    pub fn start(&self, run: &Run, mut input: impl BufRead, mut output: impl Write) -> Result<(), Error>
+    {
+        let (records, _) = run.records()?;
+        let file = run.source().path()
+            .expect("standard input can't be written to, which is checked when parsing the arguments");
+
+        // Only the arguments can be asked about, since the input is already taken by the points
+        let interactive = !self.entries.is_empty();
+        let entries = if interactive
+        {
+            self.entries.iter()
+                .map(|entry| Self::parse_entry(entry, ARG_SEPARATOR))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|error| error.at(self.exe))?
+        }
+        else
+        {
+            self.read_entries(&mut input)?
+        };
+
+        if entries.is_empty()
+        {
+            return Err(InvalidEntry::NoEntries.at(self.exe))
+        }
+
+        let teams = records.iter()
+            .map(|record| &*record.team)
+            .collect::<Vec<_>>();
+        let mut points: Vec<Option<Box<str>>> = vec![None; teams.len()];
+        let mut new_teams: Vec<TeamPoints> = Vec::new();
+        let mut row = 0;
+
+        for (team, entry_points) in entries
+        {
+            let found = match Self::find(&teams, &team)
+            {
+                Match::Sure(i) => Some(i),
+                Match::Unsure(i) if interactive && Self::confirm(&team, teams[i], &mut input, &mut output, &mut row)? => Some(i),
+                // There's no one to ask, and adding it as a new team is what "--new" is meant to guard against
+                Match::Unsure(i) if !interactive => return Err(InvalidEntry::UnsureTeam {
+                    team,
+                    suggestion: teams[i].into()
+                }.at(self.exe)),
+                Match::Unsure(i) if !self.new => return Err(InvalidEntry::UnknownTeam {
+                    team,
+                    suggestion: Some(teams[i].into())
+                }.at(self.exe)),
+                Match::Unsure(_) | Match::Unknown => None
+            };
+
+            match found
+            {
+                Some(i) => {
+                    if points[i].is_some()
+                    {
+                        return Err(InvalidEntry::DuplicateTeam {
+                            team: teams[i].into()
+                        }.at(self.exe))
+                    }
+                    points[i] = Some(entry_points);
+                },
+                None if self.new => {
+                    if new_teams.iter().any(|(other, _)| Self::simplify(other) == Self::simplify(&team))
+                    {
+                        return Err(InvalidEntry::DuplicateTeam {
+                            team
+                        }.at(self.exe))
+                    }
+                    new_teams.push((team, entry_points));
+                },
+                None => return Err(InvalidEntry::UnknownTeam {
+                    team,
+                    suggestion: None
+                }.at(self.exe))
+            }
+        }
+
+        let round = records.final_round()
+            .map_or(0, |round| round + 1);
+        let label = records.round_names().label(round);
+
+        RecordWriter::new(file, &records).add_round(round, &points, &new_teams)
+            .map_err(|error| error.at(run.source().clone()))?;
+
+        terminal::show(&mut output, format_args!("{message}\n", message = style::info(format!("Saved round {label} to \"{file}\".", file = file.display()))));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{command::AddRound, error::{Error, ExitCode, InvalidEntry, InvalidIO, InvalidRead}, tests::TempFile};
+
+    #[test]
+    fn add_round() -> Result<(), Error>
+    {
+        let file = TempFile::new("add-round", "Quizzbuzz: 3 5\nKate's Bush: 4 # Late\nBlank: 1 2\n");
+        let run = file.run(&["add-round"])?;
+        let add = |entries: &[&str], new: bool, input: &str| AddRound::new("poengsum", entries.iter().map(|&entry| entry.into()).collect(), new)
+            .start(&run, input.as_bytes(), Vec::new());
+
+        // Unknown teams are only added when asked to
+        let error = add(&["quizzbuzz=6", "Team #1=2"], false, "").unwrap_err();
+        assert!(matches!(error, Error::InvalidEntry { error: InvalidEntry::UnknownTeam { .. }, .. }));
+        let error = add(&["Blank=1", "blank=2"], false, "").unwrap_err();
+        assert!(matches!(error, Error::InvalidEntry { error: InvalidEntry::DuplicateTeam { .. }, .. }));
+        let error = add(&["Blank=1+"], false, "").unwrap_err();
+        assert_eq!(error.exit_code(), ExitCode::InvalidEntry);
+
+        // A typo that is confirmed, and one that isn't
+        add(&["Kates Bush=7.5", "Quizbuzz=6", "Blnk = 1 + 2", "Team #1=2"], true, "y\nn\ny\n")?;
+        assert_eq!(file.read(), "Quizzbuzz: 3 5\nKate's Bush: 4 - 7.5 # Late\nBlank: 1 2 1+2\nQuizbuzz: - - 6\nTeam \\#1: - - 2\n");
+
+        // Nobody can be asked about a typo in the input, even with "--new"
+        let error = add(&[], true, "Kate Bush: 1\n").unwrap_err();
+        assert!(matches!(error, Error::InvalidEntry { error: InvalidEntry::UnsureTeam { .. }, .. }));
+        let error = AddRound::new("poengsum", Vec::new(), false).start(&run, &b"Quizzbuzz: 1\n\xff\n"[..], Vec::new()).unwrap_err();
+        assert_eq!(error.exit_code(), ExitCode::InvalidIO);
+
+        // An answer that isn't text is an error too
+        let error = AddRound::new("poengsum", vec!["Kates Bush=1".into(), "Quizbuz=2".into()], false).start(&run, &b"y\n\xff\n"[..], Vec::new()).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::InvalidIO { error: InvalidIO::Read { row: 2 }, .. }, .. }));
+
+        add(&[], false, "# Round 4\nQuizzbuzz: 1\n\nkate's bush: -\n")?;
+        assert_eq!(file.read(), "Quizzbuzz: 3 5 - 1\nKate's Bush: 4 - 7.5 - # Late\nBlank: 1 2 1+2\nQuizbuzz: - - 6\nTeam \\#1: - - 2\n");
+
+        Ok(())
+    }
+}
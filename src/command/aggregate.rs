@@ -0,0 +1,75 @@
+use core::fmt::Display;
+
+use crate::{rational::Rational, score::Score};
+
+/// Points for the first ten places of each night with "placement", like in Formula 1.
+pub const PLACEMENT_POINTS: [Rational; 10] = [
+    Rational::integer(25),
+    Rational::integer(18),
+    Rational::integer(15),
+    Rational::integer(12),
+    Rational::integer(10),
+    Rational::integer(8),
+    Rational::integer(6),
+    Rational::integer(4),
+    Rational::integer(2),
+    Rational::integer(1)
+];
+
+/// What each night of a season is worth in the season standings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Aggregate
+{
+    Total,
+    Placement
+}
+
+impl Aggregate
+{
+    pub const VARIANT_COUNT: usize = 2; //core::mem::variant_count::<Aggregate>(); TODO use this instead when stable
+    pub const VARIANTS: [Aggregate; Self::VARIANT_COUNT] = [Aggregate::Total, Aggregate::Placement];
+
+    pub const fn name(self) -> &'static str
+    {
+        match self
+        {
+            Self::Total => "total",
+            Self::Placement => "placement"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        Self::VARIANTS.into_iter()
+            .find(|aggregate| aggregate.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// What the team gets in the season standings for how they did on one night, or nothing if it's too large to be exact.
+    pub fn points(self, score: &Score) -> Option<Rational>
+    {
+        match self
+        {
+            Self::Total => Some(score.points),
+            // Teams that share a place get the points for that place, and a place like 2.5 gets the points halfway between
+            Self::Placement => {
+                let points = |place: i128| usize::try_from(place - 1).ok()
+                    .and_then(|i| PLACEMENT_POINTS.get(i))
+                    .copied()
+                    .unwrap_or(Rational::ZERO);
+                let place = score.place.floor();
+                let between = score.place.checked_sub(Rational::integer(place))?;
+                points(place + 1).checked_sub(points(place))?
+                    .checked_mul(between)?
+                    .checked_add(points(place))
+            }
+        }
+    }
+}
+
+impl Display for Aggregate
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.name().fmt(f)
+    }
+}
@@ -0,0 +1,8 @@
+use super::{AddRound, Season};
+
+pub enum Command
+{
+    Enter,
+    AddRound(AddRound),
+    Season(Season)
+}
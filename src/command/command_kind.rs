@@ -0,0 +1,90 @@
+use core::fmt::Display;
+
+use crate::help::CallExample;
+
+/// Something other than showing the leaderboard, given as the first argument.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommandKind
+{
+    Enter,
+    AddRound,
+    Season
+}
+
+impl CommandKind
+{
+    pub const VARIANT_COUNT: usize = 3; //core::mem::variant_count::<CommandKind>(); TODO use this instead when stable
+    pub const VARIANTS: [CommandKind; Self::VARIANT_COUNT] = [CommandKind::Enter, CommandKind::AddRound, CommandKind::Season];
+
+    pub const fn name(self) -> &'static str
+    {
+        match self
+        {
+            Self::Enter => "enter",
+            Self::AddRound => "add-round",
+            Self::Season => "season"
+        }
+    }
+
+    /// Commands that write to the poengsum-file can only be given one.
+    pub const fn writes(self) -> bool
+    {
+        match self
+        {
+            Self::Enter | Self::AddRound => true,
+            Self::Season => false
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        Self::VARIANTS.into_iter()
+            .find(|command| command.name() == name)
+    }
+
+    pub fn examples(self, exe: &'static str) -> Vec<CallExample>
+    {
+        match self
+        {
+            Self::Enter => vec![
+                CallExample {
+                    exe,
+                    args: vec![self.name().into()],
+                    effect: Some("Asks for the points of each team in the next round, and adds them to the poengsum-file.".into())
+                }
+            ],
+            Self::AddRound => vec![
+                CallExample {
+                    exe,
+                    args: vec![self.name().into(), "Quizzbuzz=5".into(), "Kate's Bush=7.5".into()],
+                    effect: Some("Adds a round to the poengsum-file, with the points of each team.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec![self.name().into()],
+                    effect: Some("Adds a round with a \"team: points\" pair on each line of the input.".into())
+                }
+            ],
+            Self::Season => vec![
+                CallExample {
+                    exe,
+                    args: vec![self.name().into(), "nights".into()],
+                    effect: Some("Shows the season standings, with each poengsum-file in the directory \"nights\" as one quiz night.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec![self.name().into(), "january.txt".into(), "february.txt".into(), "--aggregate".into(), "placement".into(), "--best".into(), "5".into()],
+                    effect: Some("Gives points for the place on each night, and counts the 5 best nights of each team.".into())
+                }
+            ]
+        }
+    }
+}
+
+impl Display for CommandKind
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.name().fmt(f)
+    }
+}
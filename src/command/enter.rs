@@ -0,0 +1,161 @@
+use std::io::{BufRead, Write};
+
+use unicode_width::UnicodeWidthStr;
+
+use crate::{error::Error, record::{RecordParser, RecordWriter, Records, ABSENT}, run::Run, style, terminal};
+
+/// Written instead of points to go back to the previous team.
+pub const BACK: &str = "<";
+
+/// Asks for the points of each team in the next round, one team at a time, and adds them to the poengsum-file.
+pub struct Enter<'a>
+{
+    run: &'a Run
+}
+
+impl<'a> Enter<'a>
+{
+    pub fn new(run: &'a Run) -> Self
+    {
+        Self {
+            run
+        }
+    }
+
+    /// Gives `None` when there is no more input.
+    fn read(input: &mut impl BufRead, row: &mut usize) -> Result<Option<String>, Error>
+    {
+        *row += 1;
+        Ok(terminal::read_line(input, *row)?.map(|line| line.trim().to_string()))
+    }
+
+    /// Gives the points for each team, or `None` if the input ended before they were saved.
+    fn ask(records: &Records, label: &str, input: &mut impl BufRead, output: &mut impl Write) -> Result<Option<Vec<Option<Box<str>>>>, Error>
+    {
+        let teams = records.iter()
+            .map(|record| &*record.team)
+            .collect::<Vec<_>>();
+        let mut points: Vec<Option<Box<str>>> = vec![None; teams.len()];
+
+        terminal::show(output, format_args!("{header}\n", header = style::header(format!("Round {label}"))));
+        terminal::show(output, format_args!("{info}\n", info = style::info(format!("Write the points of each team, \"{ABSENT}\" if they didn't participate, or \"{BACK}\" to go back to the previous team."))));
+
+        let mut i = 0;
+        let mut row = 0;
+        loop
+        {
+            let Some(team) = teams.get(i)
+            else
+            {
+                terminal::show(output, format_args!("{prompt} ", prompt = style::info(format!("Press Enter to save round {label}, or write \"{BACK}\" to go back:"))));
+                match Self::read(input, &mut row)?
+                {
+                    None => return Ok(None),
+                    Some(line) if line == BACK => i = i.saturating_sub(1),
+                    Some(_) => return Ok(Some(points))
+                }
+                continue
+            };
+
+            let prompt = match &points[i]
+            {
+                Some(current) => format!("{team} [{current}]:"),
+                None => format!("{team}:")
+            };
+            terminal::show(output, format_args!("{prompt} ", prompt = style::team(&prompt)));
+
+            let Some(line) = Self::read(input, &mut row)?
+            else
+            {
+                return Ok(None)
+            };
+
+            if line == BACK
+            {
+                i = i.saturating_sub(1);
+                continue
+            }
+            if line.is_empty()
+            {
+                // Enter keeps what was written before
+                if points[i].is_some()
+                {
+                    i += 1;
+                }
+                continue
+            }
+            if line.contains(char::is_whitespace)
+            {
+                terminal::show(output, format_args!("{error}\n", error = style::error("Write the points as one number or sum, without spaces.")));
+                continue
+            }
+
+            match RecordParser::parse_points(&line)
+            {
+                Ok(_) => {
+                    points[i] = Some(line.into_boxed_str());
+                    i += 1;
+                },
+                Err((bad, error)) => {
+                    let offset = " ".repeat(prompt.width() + 1 + line[..bad.start].width());
+                    let arrow = "^".repeat(line[bad].width().max(1));
+                    terminal::show(output, format_args!("{offset}{error}\n", error = style::error(format!("{arrow} {error}"))));
+                }
+            }
+        }
+    }
+
+    pub fn start(&self, mut input: impl BufRead, mut output: impl Write) -> Result<(), Error>
+    {
+        let (records, _) = self.run.records()?;
+        let file = self.run.source().path()
+            .expect("standard input can't be written to, which is checked when parsing the arguments");
+
+        let round = records.final_round()
+            .map_or(0, |round| round + 1);
+        let label = records.round_names().label(round);
+
+        let points = Self::ask(&records, &label, &mut input, &mut output)?;
+
+        let message = match points
+        {
+            Some(points) => {
+                RecordWriter::new(file, &records).add_round(round, &points, &[])
+                    .map_err(|error| error.at(self.run.source().clone()))?;
+                format!("Saved round {label} to \"{file}\".", file = file.display())
+            },
+            None => "Nothing was saved.".into()
+        };
+        terminal::show(&mut output, format_args!("\n{message}\n", message = style::info(message)));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{command::Enter, error::{Error, ExitCode, InvalidIO, InvalidRead}, tests::TempFile};
+
+    #[test]
+    fn enter() -> Result<(), Error>
+    {
+        let file = TempFile::new("enter", "# Table 1\nQuizzbuzz: 3 5 # Late\nKate's Bush: 4\r\nBlank: 1 2\n");
+        let run = file.run(&["enter"])?;
+
+        // A typo, then going back to fix the first team
+        let input = "7+\n7+2\n-\n<\n<\n8\n\nx y\n1/2\n\n";
+        let mut output = Vec::new();
+        Enter::new(&run).start(input.as_bytes(), &mut output)?;
+
+        assert_eq!(file.read(), "# Table 1\nQuizzbuzz: 3 5 8 # Late\nKate's Bush: 4 - -\r\nBlank: 1 2 1/2\n");
+
+        // Input that isn't text is an error, and nothing is saved
+        let error = Enter::new(&run).start(&b"4\n\xff\n"[..], Vec::new()).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::InvalidIO { error: InvalidIO::Read { row: 2 }, .. }, .. }));
+        assert_eq!(error.exit_code(), ExitCode::InvalidIO);
+        assert_eq!(file.read(), "# Table 1\nQuizzbuzz: 3 5 8 # Late\nKate's Bush: 4 - -\r\nBlank: 1 2 1/2\n");
+
+        Ok(())
+    }
+}
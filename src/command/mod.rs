@@ -0,0 +1,10 @@
+moddef::moddef!(
+    flat(pub) mod {
+        add_round,
+        aggregate,
+        command,
+        command_kind,
+        enter,
+        season
+    }
+);
@@ -0,0 +1,195 @@
+use std::path::Path;
+
+use crate::{error::{Error, InsufficientData, InvalidIO, InvalidRead}, record::{Entry, Record, Records, Source}, round::{Count, RoundNames, Rounds, Weights}, run::Run, score::Scores, scoring::Scoring};
+
+use super::Aggregate;
+
+/// The extension of the files that are read from a directory of nights.
+pub const EXTENSION: &str = "txt";
+
+/// A league table over several quiz nights, with one poengsum-file for each night.
+pub struct Season
+{
+    aggregate: Aggregate,
+    best: Option<usize>
+}
+
+impl Season
+{
+    pub fn new(aggregate: Aggregate, best: Option<usize>) -> Self
+    {
+        Self {
+            aggregate,
+            best
+        }
+    }
+
+    /// Each file is one night, and a directory is every poengsum-file in it, in alphabetical order.
+    fn nights(sources: &[Source]) -> Result<Vec<Source>, Error>
+    {
+        let mut nights = Vec::new();
+        for source in sources
+        {
+            let Some(dir) = source.path().filter(|path| path.is_dir())
+            else
+            {
+                nights.push(source.clone());
+                continue
+            };
+
+            let mut files = std::fs::read_dir(dir)
+                .and_then(|entries| entries.map(|entry| entry.map(|entry| entry.path()))
+                    .collect::<Result<Vec<_>, _>>()
+                ).map_err(|io_error| InvalidRead::InvalidIO {
+                    io_error,
+                    error: InvalidIO::Open
+                }.at(source.clone()))?;
+            files.retain(|file| file.is_file() && file.extension().is_some_and(|extension| extension == EXTENSION));
+            files.sort();
+
+            nights.extend(files.into_iter().map(|file| Source::File(file.into())));
+        }
+        Ok(nights)
+    }
+
+    /// The name of the file, without the directory and extension.
+    fn name(night: &Source) -> Box<str>
+    {
+        match night.path().and_then(Path::file_stem)
+        {
+            Some(name) => name.to_string_lossy().into(),
+            None => night.to_string().into_boxed_str()
+        }
+    }
+
+    pub fn scores(&self, run: &Run) -> Result<Scores, Error>
+    {
+        let nights = Self::nights(run.sources())?;
+        if nights.is_empty()
+        {
+            return Err(InsufficientData::NoNights.into())
+        }
+
+        let mut teams: Vec<Box<str>> = Vec::new();
+        let mut points: Vec<Vec<Entry>> = Vec::new();
+        for (night, source) in nights.iter().enumerate()
+        {
+            let (records, warnings) = run.read(source)?;
+            if !warnings.is_empty()
+            {
+                eprintln!("{warnings}\n", warnings = InvalidRead::warnings(&warnings, source));
+            }
+
+            let scoring = Scoring {
+                rounds: Rounds::All,
+                count: run.scoring().count,
+                tie_breaks: run.scoring().tie_breaks.clone(),
+                ranking: run.scoring().ranking,
+                // Each night has its own rounds, so the weights and the round to compare with on the commandline are for the nights instead
+                weights: None,
+                since: None
+            };
+            let scores = Scores::new(records, scoring, run.output())?;
+            for score in scores.ordered()
+            {
+                let i = match teams.iter().position(|team| *team == score.team)
+                {
+                    Some(i) => i,
+                    None => {
+                        teams.push(score.team.clone());
+                        points.push(Vec::new());
+                        teams.len() - 1
+                    }
+                };
+
+                // A team listed twice on the same night only counts once
+                if points[i].len() > night
+                {
+                    continue
+                }
+                points[i].resize(night, Entry::Absent);
+                points[i].push(Entry::Points {
+                    points: self.aggregate.points(score)
+                        .ok_or_else(|| Error::PointsTooLarge {
+                            team: score.team.clone()
+                        })?,
+                    expression: None,
+                    joker: false
+                });
+            }
+        }
+
+        let records = teams.into_iter()
+            .zip(points)
+            .map(|(team, points)| Record::new(team, points));
+        let names = nights.iter()
+            .map(|night| Some(Self::name(night)));
+        let records = Records::collect_from(records, RoundNames::new(names), [].into(), Weights::default())
+            .map_err(|error| InvalidRead::from(error).at(nights[0].clone()))?;
+
+        // The climb is compared to the standings after the night before, or the nights given with "--since"
+        let scoring = Scoring {
+            rounds: Rounds::All,
+            count: self.best.map_or(Count::All, Count::Best),
+            tie_breaks: run.scoring().tie_breaks.clone(),
+            ranking: run.scoring().ranking,
+            weights: run.scoring().weights.clone(),
+            since: run.scoring().since.clone()
+        };
+        Scores::new(records, scoring, run.output())
+    }
+
+    pub fn start(&self, run: &Run) -> Result<(), Error>
+    {
+        self.scores(run)?.present();
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{command::{Aggregate, Season}, error::{Error, InvalidArg, InvalidCall, InvalidFlag}, record::Source, tests::{self, TempFile}};
+
+    #[test]
+    fn season() -> Result<(), Error>
+    {
+        let nights = TempFile::dir("season");
+        nights.write_in("2026-01.txt", "Quizzbuzz: 5 5\nKate's Bush: 8 1\nBlank: 3 3\n");
+        nights.write_in("2026-02.txt", "Quizzbuzz: 1\nKate's Bush: 9\nTable \\#7: 4\n");
+        nights.write_in("notes.md", "Not a night");
+
+        let run = tests::run(&["season", nights.path(), "--output", "csv"])?;
+        let season = |aggregate: Aggregate, best: Option<usize>| -> Result<String, Error> {
+            Ok(Season::new(aggregate, best).scores(&run)?.to_string())
+        };
+
+        assert_eq!(
+            season(Aggregate::Total, None)?,
+            "place,team,2026-01,2026-02,total,climb\r\n1,Kate's Bush,9,9,18,1\r\n2,Quizzbuzz,10,1,11,-1\r\n3,Blank,6,,6,0\r\n4,Table #7,,4,4,0\r\n"
+        );
+        assert_eq!(
+            season(Aggregate::Placement, None)?,
+            "place,team,2026-01,2026-02,total,climb\r\n1,Kate's Bush,18,25,43,1\r\n2,Quizzbuzz,25,15,40,-1\r\n3,Table #7,,18,18,1\r\n4,Blank,15,,15,-1\r\n"
+        );
+        assert_eq!(
+            season(Aggregate::Total, Some(1))?,
+            "place,team,2026-01,2026-02,total,climb\r\n1,Quizzbuzz,10,1,10,0\r\n2,Kate's Bush,9,9,9,0\r\n3,Blank,6,,6,0\r\n4,Table #7,,4,4,0\r\n"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn night_from_stdin() -> Result<(), Error>
+    {
+        let run = tests::run(&["season", "-"])?;
+        assert!(matches!(run.sources(), [Source::Stdin]));
+
+        // Standard input can only be read once, whether it's given as a night or with "--file"
+        let error = tests::run(&["season", "-", "--file", "-"]).err().unwrap();
+        assert!(matches!(error, Error::InvalidCall { error: InvalidCall::InvalidArg { error: InvalidArg::InvalidFlag { error: InvalidFlag::FileAlreadySpecified }, .. }, .. }));
+
+        Ok(())
+    }
+}
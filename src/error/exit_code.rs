@@ -0,0 +1,91 @@
+use super::{Error, InsufficientData, InvalidCall, InvalidRead};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ExitCode
+{
+    Success = 0,
+    ShowHelp = 1,
+    InvalidArg = 2,
+    ExpectedArg = 3,
+    InvalidIO = 4,
+    InvalidSyntax = 5,
+    InsufficientData = 6,
+    NoExecutable = 7,
+    InvalidEntry = 8,
+    PointsTooLarge = 9
+}
+
+impl ExitCode
+{
+    pub const VARIANT_COUNT: usize = 10; //core::mem::variant_count::<ExitCode>(); TODO use this instead when stable
+    pub const VARIANTS: [ExitCode; Self::VARIANT_COUNT] = [
+        ExitCode::Success,
+        ExitCode::ShowHelp,
+        ExitCode::InvalidArg,
+        ExitCode::ExpectedArg,
+        ExitCode::InvalidIO,
+        ExitCode::InvalidSyntax,
+        ExitCode::InsufficientData,
+        ExitCode::NoExecutable,
+        ExitCode::InvalidEntry,
+        ExitCode::PointsTooLarge
+    ];
+
+    pub const fn code(self) -> u8
+    {
+        self as u8
+    }
+
+    pub fn description(self) -> &'static str
+    {
+        match self
+        {
+            ExitCode::Success => "The leaderboard, or the help that was asked for, was shown.",
+            ExitCode::ShowHelp => "Help was shown instead of an error, for an argument after \"--help\" that is invalid or missing.",
+            ExitCode::InvalidArg => "One of the commandline arguments is invalid, or names a round that isn't in the poengsum-file.",
+            ExitCode::ExpectedArg => "A commandline argument is missing.",
+            ExitCode::InvalidIO => "The poengsum-file could not be opened or read.",
+            ExitCode::InvalidSyntax => "The poengsum-file contains a syntax error, or a warning with \"--strict\".",
+            ExitCode::InsufficientData => "There are no results for the chosen rounds.",
+            ExitCode::NoExecutable => "The program was run without even a 0th argument.",
+            ExitCode::InvalidEntry => "The points given to a command are invalid.",
+            ExitCode::PointsTooLarge => "The points of a team are too large to add up exactly."
+        }
+    }
+}
+
+impl From<ExitCode> for std::process::ExitCode
+{
+    fn from(code: ExitCode) -> Self
+    {
+        code.code().into()
+    }
+}
+
+impl Error
+{
+    pub fn exit_code(&self) -> ExitCode
+    {
+        match self
+        {
+            Error::InvalidRead { file: _, error } => match error
+            {
+                InvalidRead::InvalidIO { .. } => ExitCode::InvalidIO,
+                InvalidRead::InvalidSyntax { .. } | InvalidRead::Strict { .. } => ExitCode::InvalidSyntax
+            },
+            Error::InvalidCall { exe: _, no: _, error } => match error
+            {
+                InvalidCall::InvalidArg { .. } => ExitCode::InvalidArg,
+                InvalidCall::ExpectedArg { .. } => ExitCode::ExpectedArg
+            },
+            // The name can't be checked until the file is read, but it's still a mistake in the arguments
+            Error::InsufficientData { error: InsufficientData::UnknownRound { .. } } => ExitCode::InvalidArg,
+            Error::InsufficientData { .. } => ExitCode::InsufficientData,
+            Error::NoExecutable => ExitCode::NoExecutable,
+            Error::InvalidEntry { .. } => ExitCode::InvalidEntry,
+            Error::PointsTooLarge { .. } => ExitCode::PointsTooLarge,
+            Error::ShowHelp { help } => if help.caught() {ExitCode::ShowHelp} else {ExitCode::Success}
+        }
+    }
+}
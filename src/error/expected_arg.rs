@@ -5,7 +5,17 @@ use super::{Error, Msg, InvalidCall};
 #[derive(Debug)]
 pub enum ExpectedArg
 {
-    Filename
+    Filename,
+    Format,
+    Delay,
+    Aggregate,
+    Best,
+    TieBreak,
+    Ranking,
+    Weights,
+    Count,
+    Decimals,
+    Since
 }
 
 struct ExpectedArgMsg;
@@ -40,12 +50,123 @@ impl ExpectedArg
                 msg: ExpectedArgMsg.msg(no, "a filename"),
                 error: None,
                 line: None,
+                related: None,
                 hint: None,
                 docs: Some(Docs::FlagUsage(FlagUsage {
                     exe,
                     flag: FlagKind::File
                 }))
             },
+            ExpectedArg::Format => Msg {
+                msg: ExpectedArgMsg.msg(no, "an output format"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Output
+                }))
+            },
+            ExpectedArg::Delay => Msg {
+                msg: ExpectedArgMsg.msg(no, "a number of seconds"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Delay
+                }))
+            },
+            ExpectedArg::Aggregate => Msg {
+                msg: ExpectedArgMsg.msg(no, "a way to add up the nights"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Aggregate
+                }))
+            },
+            ExpectedArg::Best => Msg {
+                msg: ExpectedArgMsg.msg(no, "a number of nights"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Best
+                }))
+            },
+            ExpectedArg::TieBreak => Msg {
+                msg: ExpectedArgMsg.msg(no, "tie-break rules"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::TieBreak
+                }))
+            },
+            ExpectedArg::Ranking => Msg {
+                msg: ExpectedArgMsg.msg(no, "a ranking"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Ranking
+                }))
+            },
+            ExpectedArg::Weights => Msg {
+                msg: ExpectedArgMsg.msg(no, "the weight of each round"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Weights
+                }))
+            },
+            ExpectedArg::Count => Msg {
+                msg: ExpectedArgMsg.msg(no, "which rounds to count"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Count
+                }))
+            },
+            ExpectedArg::Decimals => Msg {
+                msg: ExpectedArgMsg.msg(no, "a number of decimal places"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Decimals
+                }))
+            },
+            ExpectedArg::Since => Msg {
+                msg: ExpectedArgMsg.msg(no, "a round to compare with"),
+                error: None,
+                line: None,
+                related: None,
+                hint: None,
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Since
+                }))
+            },
         }
     }
 }
\ No newline at end of file
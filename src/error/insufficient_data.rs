@@ -1,3 +1,5 @@
+use crate::{command::{CommandKind, EXTENSION}, help::{Docs, Rules}, record::{Directive, DIRECTIVE}};
+
 use super::{Error, Msg};
 
 #[derive(Debug)]
@@ -7,8 +9,14 @@ pub enum InsufficientData
     NoRoundsYet,
     RoundNotYet {
         round: usize,
+        name: Option<Box<str>>,
         final_round: usize
-    }
+    },
+    UnknownRound {
+        name: Box<str>,
+        names: Box<[Box<str>]>
+    },
+    NoNights
 }
 
 impl InsufficientData
@@ -21,6 +29,7 @@ impl InsufficientData
                 msg: "No results.".into(),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some("The range of rounds provided is empty.".into()),
                 docs: None
             },
@@ -28,14 +37,46 @@ impl InsufficientData
                 msg: "No results.".into(),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some("None of the teams have gotten any points yet!".into()),
                 docs: None
             },
-            InsufficientData::RoundNotYet { round, final_round } => Msg {
-                msg: format!("Round {round} hasn't happened yet.").into_boxed_str(),
+            InsufficientData::RoundNotYet { round, name, final_round } => {
+                let round = round + 1;
+                let rounds = final_round + 1;
+                let name = name.as_ref()
+                    .map(|name| format!(" ({name})"))
+                    .unwrap_or_default();
+                Msg {
+                    msg: format!("Round {round}{name} hasn't happened yet.").into_boxed_str(),
+                    error: None,
+                    line: None,
+                    related: None,
+                    hint: Some(format!("There has only been {rounds} rounds so far!").into_boxed_str()),
+                    docs: None
+                }
+            },
+            InsufficientData::UnknownRound { name, names } => Msg {
+                msg: format!("There is no round named \"{name}\".").into_boxed_str(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(if names.is_empty()
+                {
+                    format!("None of the rounds have names. You can name them with \"{DIRECTIVE}{rounds}:\" in the poengsum-file.", rounds = Directive::Rounds).into_boxed_str()
+                }
+                else
+                {
+                    format!("The rounds are named: {names}.", names = names.join(", ")).into_boxed_str()
+                }),
+                docs: Some(Docs::Rules(Rules))
+            },
+            InsufficientData::NoNights => Msg {
+                msg: "No results.".into(),
                 error: None,
                 line: None,
-                hint: Some(format!("There has only been {final_round} rounds so far!").into_boxed_str()),
+                related: None,
+                hint: Some(format!("There are no poengsum-files for the season. Give \"{season}\" the files for each night, or a directory with a \".{EXTENSION}\"-file for each night.", season = CommandKind::Season).into_boxed_str()),
                 docs: None
             },
         }
@@ -1,6 +1,6 @@
 use core::{ num::ParseIntError, str::FromStr};
 
-use crate::{flag::{FlagKind, FlagOption}, help::{ArgUsage, Docs, FlagUsage, FlagsUsages}};
+use crate::{command::{Aggregate, CommandKind}, flag::{FlagKind, FlagOption}, help::{ArgUsage, CommandsUsages, Docs, FlagUsage, FlagsUsages}, output::Notation, round::Count, scoring::{Ranking, TieBreak}};
 
 use super::{Error, Msg, InvalidCall, InvalidFlag};
 
@@ -19,10 +19,40 @@ pub enum InvalidArg
     NonexistentFlag {
         flag: FlagOption<Box<str>>
     },
+    NonexistentFormat {
+        format: Box<str>
+    },
+    InvalidDelay {
+        delay: Box<str>
+    },
+    NonexistentAggregate {
+        aggregate: Box<str>
+    },
+    InvalidBest {
+        best: Box<str>
+    },
+    NonexistentTieBreak {
+        tie_break: Box<str>
+    },
+    NonexistentRanking {
+        ranking: Box<str>
+    },
+    InvalidWeight {
+        weight: Box<str>
+    },
+    InvalidCount {
+        count: Box<str>
+    },
+    InvalidDecimals {
+        decimals: Box<str>
+    },
     InvalidFlag {
         error: InvalidFlag
     },
-    IntegerAfterHelp
+    IntegerAfterHelp,
+    ArgAfterCommand {
+        command: CommandKind
+    }
 }
 
 impl From<ParseIntError> for InvalidArg
@@ -76,6 +106,7 @@ impl InvalidArg
                 msg: InvalidArgMsg::Invalid.msg(no, arg),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some("Did you mean to write '..' instead of '.'?".into()),
                 docs: Some(Docs::ArgUsage(ArgUsage {
                     exe
@@ -85,6 +116,7 @@ impl InvalidArg
                 msg: InvalidArgMsg::Parse.msg(no, arg),
                 error: Some(error),
                 line: None,
+                related: None,
                 hint: Some("Round must be a valid number (a nonzero positive integer).".into()),
                 docs: Some(Docs::ArgUsage(ArgUsage {
                     exe
@@ -94,6 +126,7 @@ impl InvalidArg
                 msg: InvalidArgMsg::Invalid.msg(no, arg),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some(format!("{start} is larger than {end}, but this is not supported.").into_boxed_str()),
                 docs: Some(Docs::ArgUsage(ArgUsage {
                     exe
@@ -103,6 +136,7 @@ impl InvalidArg
                 msg: InvalidArgMsg::Invalid.msg(no, arg),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some("0 is not a valid round! Rounds start at 1, not 0.".into()),
                 docs: Some(Docs::ArgUsage(ArgUsage {
                     exe
@@ -112,21 +146,152 @@ impl InvalidArg
                 msg: InvalidArgMsg::Invalid.msg(no, arg),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some(format!("There is no available option with the name \"{flag}\".").into_boxed_str()),
                 docs: Some(Docs::FlagsUsages(FlagsUsages {
                     exe
                 }))
             },
+            InvalidArg::NonexistentFormat { format } => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!("There is no available output format with the name \"{format}\".").into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Output
+                }))
+            },
+            InvalidArg::InvalidDelay { delay } => Msg {
+                msg: InvalidArgMsg::Parse.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!("\"{delay}\" is not a valid delay. It must be a positive number of seconds, like \"5\" or \"2.5\".").into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Delay
+                }))
+            },
+            InvalidArg::NonexistentAggregate { aggregate } => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "There is no way to add up the nights with the name \"{aggregate}\". Use \"{total}\" or \"{placement}\".",
+                    total = Aggregate::Total,
+                    placement = Aggregate::Placement
+                ).into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Aggregate
+                }))
+            },
+            InvalidArg::InvalidBest { best } => Msg {
+                msg: InvalidArgMsg::Parse.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!("\"{best}\" is not a valid number of nights. It must be a whole number larger than 0.").into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Best
+                }))
+            },
+            InvalidArg::NonexistentTieBreak { tie_break } => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "There is no tie-break with the name \"{tie_break}\". Use one or more of: {tie_breaks}.",
+                    tie_breaks = TieBreak::VARIANTS.map(TieBreak::name).join(", ")
+                ).into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::TieBreak
+                }))
+            },
+            InvalidArg::NonexistentRanking { ranking } => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "There is no ranking with the name \"{ranking}\". Use one of: {rankings}.",
+                    rankings = Ranking::VARIANTS.map(Ranking::name).join(", ")
+                ).into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Ranking
+                }))
+            },
+            InvalidArg::InvalidWeight { weight } => Msg {
+                msg: InvalidArgMsg::Parse.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!("\"{weight}\" is not a valid weight. It must be a number that isn't negative, like \"2\" or \"1.5\".").into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Weights
+                }))
+            },
+            InvalidArg::InvalidCount { count } => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "\"{count}\" is not a way to count rounds. Use \"{all}\", \"{present}\", or \"{best}\" or \"{drop}\" followed by a number, like \"{best_5}\".",
+                    all = Count::All,
+                    present = Count::Present,
+                    best = Count::BEST,
+                    drop = Count::DROP,
+                    best_5 = Count::Best(5)
+                ).into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Count
+                }))
+            },
+            InvalidArg::InvalidDecimals { decimals } => Msg {
+                msg: InvalidArgMsg::Parse.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "\"{decimals}\" is not a valid number of decimal places. It must be a whole number from 0 to {max_decimals}.",
+                    max_decimals = Notation::MAX_DECIMALS
+                ).into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Decimals
+                }))
+            },
             InvalidArg::InvalidFlag { error } => error.msg(exe, no, arg),
             InvalidArg::IntegerAfterHelp => Msg {
                 msg: InvalidArgMsg::Invalid.msg(no, arg),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some(format!("Didn't expect argument{arg} after \"--help\".").into_boxed_str()),
                 docs: Some(Docs::FlagUsage(FlagUsage {
                     exe,
                     flag: FlagKind::Help
                 }))
+            },
+            InvalidArg::ArgAfterCommand { command } => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!("\"{command}\" only takes flags, like \"--file\".").into_boxed_str()),
+                docs: Some(Docs::CommandsUsages(CommandsUsages {
+                    exe
+                }))
             }
         }
     }
@@ -0,0 +1,121 @@
+use core::ops::Range;
+
+use crate::{command::CommandKind, flag::FlagKind, help::{CommandsUsages, Docs}, record::ABSENT};
+
+use super::{Error, InvalidExpression, Msg};
+
+/// Points given to a command, rather than written in the poengsum-file.
+#[derive(Debug)]
+pub enum InvalidEntry
+{
+    NoEntries,
+    MissingSeparator {
+        entry: Box<str>,
+        separator: char
+    },
+    UnnamedTeam {
+        entry: Box<str>
+    },
+    CannotParsePoints {
+        team: Box<str>,
+        points: Box<str>,
+        col: Range<usize>,
+        error: InvalidExpression
+    },
+    UnknownTeam {
+        team: Box<str>,
+        suggestion: Option<Box<str>>
+    },
+    /// A name close to a team in the poengsum-file, that there was no one to ask about.
+    UnsureTeam {
+        team: Box<str>,
+        suggestion: Box<str>
+    },
+    DuplicateTeam {
+        team: Box<str>
+    }
+}
+
+impl InvalidEntry
+{
+    pub fn at(self, exe: &'static str) -> Error
+    {
+        Error::InvalidEntry {
+            exe,
+            error: self
+        }
+    }
+
+    pub fn msg(&self, exe: &'static str) -> Msg<'_>
+    {
+        let docs = Some(Docs::CommandsUsages(CommandsUsages {
+            exe
+        }));
+        match self
+        {
+            InvalidEntry::NoEntries => Msg {
+                msg: "No points were given.".into(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some("Write \"team=points\" for each team after the command, or a \"team: points\" pair on each line of the input.".into()),
+                docs
+            },
+            InvalidEntry::MissingSeparator { entry, separator } => Msg {
+                msg: format!("Separator '{separator}' missing in \"{entry}\".").into_boxed_str(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!("Use a '{separator}' to separate the team name and their points.").into_boxed_str()),
+                docs
+            },
+            InvalidEntry::UnnamedTeam { entry } => Msg {
+                msg: format!("Points \"{entry}\" were given without a team.").into_boxed_str(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some("Write the name of the team before the points.".into()),
+                docs
+            },
+            InvalidEntry::CannotParsePoints { team, points, col, error } => Msg {
+                msg: format!("Unable to parse points for team \"{team}\" at \"{span}\" in \"{points}\".", span = &points[col.clone()]).into_boxed_str(),
+                error: Some(error),
+                line: None,
+                related: None,
+                hint: Some(format!("The points must be a number or a sum, like \"7+2\", or \"{ABSENT}\" if the team didn't participate in the round.").into_boxed_str()),
+                docs
+            },
+            InvalidEntry::UnknownTeam { team, suggestion } => Msg {
+                msg: format!("There is no team named \"{team}\" in the poengsum-file.").into_boxed_str(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(match suggestion
+                {
+                    Some(suggestion) => format!("Did you mean \"{suggestion}\"? Use \"--{new}\" if it's a new team.", new = FlagKind::New),
+                    None => format!("Use \"--{new}\" to add it as a new team.", new = FlagKind::New)
+                }.into_boxed_str()),
+                docs
+            },
+            InvalidEntry::UnsureTeam { team, suggestion } => Msg {
+                msg: format!("The team \"{team}\" is not in the poengsum-file, but \"{suggestion}\" is.").into_boxed_str(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "Points read from the input can't be asked about. Write \"{suggestion}\" if it's the same team, or give \"{team}=points\" after \"{add_round}\" to be asked.",
+                    add_round = CommandKind::AddRound
+                ).into_boxed_str()),
+                docs
+            },
+            InvalidEntry::DuplicateTeam { team } => Msg {
+                msg: format!("The team \"{team}\" was given points more than once.").into_boxed_str(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!("Each team can only get points once in each \"{add_round}\".", add_round = CommandKind::AddRound).into_boxed_str()),
+                docs
+            }
+        }
+    }
+}
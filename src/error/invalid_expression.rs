@@ -0,0 +1,21 @@
+use super::InvalidNumber;
+
+#[derive(thiserror::Error, Debug)]
+pub enum InvalidExpression
+{
+    #[error("{error}")]
+    InvalidNumber {
+        #[from]
+        error: InvalidNumber
+    },
+    #[error("unexpected '{c}'")]
+    UnexpectedChar {
+        c: char
+    },
+    #[error("expected a number")]
+    ExpectedNumber,
+    #[error("the '(' is never closed")]
+    UnclosedParenthesis,
+    #[error("division by zero")]
+    DivisionByZero
+}
@@ -1,4 +1,4 @@
-use crate::{flag::FlagKind, help::{Docs, FlagUsage}};
+use crate::{command::CommandKind, flag::FlagKind, help::{Docs, FlagUsage}, record::STDIN};
 
 use super::{Error, Msg, InvalidArg, InvalidArgMsg};
 
@@ -6,7 +6,9 @@ use super::{Error, Msg, InvalidArg, InvalidArgMsg};
 pub enum InvalidFlag
 {
     FileAlreadySpecified,
-    HelpAfterInteger
+    HelpAfterInteger,
+    StdinInUse,
+    RevealWhileWatching
 }
 
 impl From<InvalidFlag> for InvalidArg
@@ -29,7 +31,9 @@ impl InvalidFlag
         match self
         {
             Self::FileAlreadySpecified => FlagKind::File,
-            Self::HelpAfterInteger => FlagKind::Help
+            Self::HelpAfterInteger => FlagKind::Help,
+            Self::StdinInUse => FlagKind::File,
+            Self::RevealWhileWatching => FlagKind::Watch
         }
     }
 
@@ -41,7 +45,12 @@ impl InvalidFlag
                 msg: InvalidArgMsg::Invalid.msg(no, arg),
                 error: None,
                 line: None,
-                hint: Some("You've already specified a filename.".into()),
+                related: None,
+                hint: Some(format!(
+                    "You've already specified a filename. More than one file can be read at once, but \"{enter}\" and \"{add_round}\" only write to one, and standard input can only be read once.",
+                    enter = CommandKind::Enter,
+                    add_round = CommandKind::AddRound
+                ).into_boxed_str()),
                 docs: Some(Docs::FlagUsage(FlagUsage {
                     exe,
                     flag: FlagKind::File
@@ -51,11 +60,46 @@ impl InvalidFlag
                 msg: InvalidArgMsg::Invalid.msg(no, arg),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some(format!("You can't use{arg} after integer arguments.").into_boxed_str()),
                 docs: Some(Docs::FlagUsage(FlagUsage {
                     exe,
                     flag: FlagKind::Help
                 }))
+            },
+            InvalidFlag::StdinInUse => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "The poengsum-file can't be read from standard input (\"--{file} {STDIN}\") with \"--{watch}\", \"{enter}\" or \"{add_round}\", since they need to read it again or write to it.",
+                    file = FlagKind::File,
+                    watch = FlagKind::Watch,
+                    enter = CommandKind::Enter,
+                    add_round = CommandKind::AddRound
+                ).into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::File
+                }))
+            },
+            InvalidFlag::RevealWhileWatching => Msg {
+                msg: InvalidArgMsg::Invalid.msg(no, arg),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some(format!(
+                    "\"--{watch}\" redraws the whole leaderboard every time the file is saved, so it can't be combined with \"--{reveal}\", \"--{delay}\" or \"--{podium}\".",
+                    watch = FlagKind::Watch,
+                    reveal = FlagKind::Reveal,
+                    delay = FlagKind::Delay,
+                    podium = FlagKind::Podium
+                ).into_boxed_str()),
+                docs: Some(Docs::FlagUsage(FlagUsage {
+                    exe,
+                    flag: FlagKind::Watch
+                }))
             }
         }
     }
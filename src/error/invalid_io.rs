@@ -1,6 +1,6 @@
-use std::{io::ErrorKind, path::Path};
+use std::io::ErrorKind;
 
-use crate::help::{Docs, Rules};
+use crate::{help::{Docs, Rules}, record::Source};
 
 use super::{Msg, Severity, OffendingLine};
 
@@ -10,18 +10,17 @@ pub enum InvalidIO
     Open,
     Read {
         row: usize
-    }
+    },
+    Write
 }
 
 impl InvalidIO
 {
-    pub fn msg<'a>(&'a self, file: &'a Path, io_error: &'a std::io::Error) -> Msg<'a>
+    pub fn msg<'a>(&'a self, file: &'a Source, io_error: &'a std::io::Error) -> Msg<'a>
     {
-        let file_display = file.display();
-
         let hint = |placeholder: Option<Box<str>>| match io_error.kind()
         {
-            ErrorKind::NotFound => if file == crate::default_file_path()
+            ErrorKind::NotFound => if file.path() == Some(crate::default_file_path())
             {
                 (
                     Some("If it doesn't exist, create it!".into()),
@@ -40,7 +39,7 @@ impl InvalidIO
                 None
             ),
             ErrorKind::IsADirectory => (
-                Some(format!("There's, for some reason, a directory with the same name as {file_display}.\nThis program needs {file_display} to be a file, not a directory.").into_boxed_str()),
+                Some(format!("There's, for some reason, a directory with the same name as {file}.\nThis program needs {file} to be a file, not a directory.").into_boxed_str()),
                 None
             ),
             ErrorKind::Other => (
@@ -58,9 +57,10 @@ impl InvalidIO
             InvalidIO::Open => {
                 let (hint, docs) = hint(None);
                 Msg {
-                    msg: format!("Failed to open file \"{file_display}\".").into_boxed_str(),
+                    msg: format!("Failed to open file \"{file}\".").into_boxed_str(),
                     error: Some(io_error),
                     line: None,
+                    related: None,
                     hint,
                     docs
                 }
@@ -68,7 +68,7 @@ impl InvalidIO
             InvalidIO::Read { row } => {
                 let (hint, docs) = hint(None);
                 Msg {
-                    msg: format!("Failed to open file \"{file_display}\".").into_boxed_str(),
+                    msg: format!("Failed to open file \"{file}\".").into_boxed_str(),
                     error: Some(io_error),
                     line: Some(OffendingLine {
                         file,
@@ -77,6 +77,18 @@ impl InvalidIO
                         row: *row,
                         col: None
                     }),
+                    related: None,
+                    hint,
+                    docs
+                }
+            },
+            InvalidIO::Write => {
+                let (hint, docs) = hint(None);
+                Msg {
+                    msg: format!("Failed to write to file \"{file}\".").into_boxed_str(),
+                    error: Some(io_error),
+                    line: None,
+                    related: None,
                     hint,
                     docs
                 }
@@ -0,0 +1,10 @@
+#[derive(thiserror::Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InvalidNumber
+{
+    #[error("cannot parse a number from an empty string")]
+    Empty,
+    #[error("invalid digit found in string")]
+    InvalidDigit,
+    #[error("number too large to be exact")]
+    TooLarge
+}
@@ -1,6 +1,6 @@
-use std::{borrow::Cow, path::Path};
+use crate::record::Source;
 
-use super::{Error, Msg, InvalidIO, InvalidSyntax};
+use super::{Error, InvalidIO, InvalidSyntax, Report, Severity, Warning};
 
 #[derive(Debug)]
 pub enum InvalidRead
@@ -10,13 +10,17 @@ pub enum InvalidRead
         error: InvalidIO
     },
     InvalidSyntax {
-        error: InvalidSyntax
+        errors: Box<[InvalidSyntax]>
     },
+    /// Warnings are treated as errors with "--strict".
+    Strict {
+        warnings: Box<[Warning]>
+    }
 }
 
 impl InvalidRead
 {
-    pub fn at(self, file: Cow<'static, Path>) -> Error
+    pub fn at(self, file: Source) -> Error
     {
         Error::InvalidRead {
             file,
@@ -24,12 +28,37 @@ impl InvalidRead
         }
     }
 
-    pub fn msg<'a>(&'a self, file: &'a Path) -> Msg<'a>
+    fn count(n: usize, what: &str) -> String
+    {
+        match n
+        {
+            1 => format!("1 {what}"),
+            n => format!("{n} {what}s")
+        }
+    }
+
+    /// Warnings that aren't errors are printed above the leaderboard.
+    pub fn warnings<'a>(warnings: &'a [Warning], file: &'a Source) -> Report<'a>
+    {
+        Report::new(
+            warnings.iter().map(|warning| warning.msg(file, Severity::Warning)),
+            Some((Severity::Warning, format!("Found {count} in \"{file}\".", count = Self::count(warnings.len(), "warning")).into_boxed_str()))
+        )
+    }
+
+    pub fn report<'a>(&'a self, file: &'a Source) -> Report<'a>
     {
         match self
         {
-            InvalidRead::InvalidIO { io_error, error } => error.msg(file, io_error),
-            InvalidRead::InvalidSyntax { error } => error.msg(file),
+            InvalidRead::InvalidIO { io_error, error } => error.msg(file, io_error).into(),
+            InvalidRead::InvalidSyntax { errors } => Report::new(
+                errors.iter().map(|error| error.msg(file)),
+                Some((Severity::Error, format!("Found {count} in \"{file}\".", count = Self::count(errors.len(), "error")).into_boxed_str()))
+            ),
+            InvalidRead::Strict { warnings } => Report::new(
+                warnings.iter().map(|warning| warning.msg(file, Severity::Error)),
+                Some((Severity::Error, format!("Found {count} in \"{file}\", which are errors with \"--strict\".", count = Self::count(warnings.len(), "warning")).into_boxed_str()))
+            )
         }
     }
 }
\ No newline at end of file
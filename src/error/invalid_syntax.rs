@@ -1,9 +1,8 @@
-use core::{num::ParseFloatError, ops::Range};
-use std::path::Path;
+use core::ops::Range;
 
-use crate::help::{Docs, Rules};
+use crate::{help::{Docs, Rules}, record::{Directive, RecordParser, Source, ABSENT, COMMENT, DIRECTIVE, ESCAPE, JOKER}, scoring::TieBreak};
 
-use super::{Msg, InvalidRead, Severity, OffendingLine};
+use super::{Msg, InvalidExpression, InvalidRead, Severity, OffendingLine};
 
 #[derive(Debug)]
 pub enum InvalidSyntax
@@ -24,19 +23,72 @@ pub enum InvalidSyntax
         round: usize,
         line: Box<str>,
         team: Box<str>,
-        error: ParseFloatError
+        error: InvalidExpression
     },
+    DuplicateJoker {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        team: Box<str>,
+        round: usize,
+        first_round: usize
+    },
+    UnknownDirective {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>
+    },
+    DuplicateDirective {
+        row: usize,
+        line: Box<str>,
+        directive: Directive,
+        first_row: usize
+    },
+    InvalidRoundName {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        name: Box<str>
+    },
+    DuplicateRoundName {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        name: Box<str>
+    },
+    UnknownTieBreak {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        name: Box<str>
+    },
+    InvalidWeight {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        weight: Box<str>
+    },
+    ConflictingPoints {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        team: Box<str>,
+        round: Box<str>,
+        first_file: Box<Source>,
+        first_row: usize,
+        first_col: Range<usize>,
+        first_line: Box<str>
+    }
 }
 
 impl InvalidSyntax
 {
-    pub fn msg<'a>(&'a self, file: &'a Path) -> Msg<'a>
+    pub fn msg<'a>(&'a self, file: &'a Source) -> Msg<'a>
     {
-        let file_display = file.display();
         match self
         {
             InvalidSyntax::NoTeams => Msg {
-                msg: format!("The file \"{file_display}\" is empty!").into_boxed_str(),
+                msg: format!("The file \"{file}\" is empty!").into_boxed_str(),
                 error: None,
                 line: Some(OffendingLine {
                     file,
@@ -45,11 +97,105 @@ impl InvalidSyntax
                     row: 0,
                     col: None
                 }),
-                hint: Some(format!("You need to add some teams to your \"{file_display}\"-file.").into_boxed_str()),
+                related: None,
+                hint: Some(format!("You need to add some teams to your \"{file}\"-file.").into_boxed_str()),
                 docs: Some(Docs::Rules(Rules))
             },
-            InvalidSyntax::MissingColon { row, line } => Msg {
-                msg: format!("Seperator ':' missing at line {row}.").into_boxed_str(),
+            InvalidSyntax::MissingColon { row, line } => {
+                let content_end = RecordParser::content_end(line);
+                let commented_out = line[content_end..].contains(':');
+                Msg {
+                    msg: format!("Seperator ':' missing at line {row}.").into_boxed_str(),
+                    error: None,
+                    line: Some(OffendingLine {
+                        file,
+                        severity: Severity::Error,
+                        line: Some(&**line),
+                        row: *row,
+                        col: commented_out.then_some(content_end..content_end + COMMENT.len_utf8())
+                    }),
+                    related: None,
+                    hint: Some(if commented_out
+                    {
+                        format!("Everything after '{COMMENT}' is a comment. Write '{ESCAPE}{COMMENT}' if the '{COMMENT}' is part of the team name.").into_boxed_str()
+                    }
+                    else
+                    {
+                        "Use a ':' to separate the team name and their points for each round.".into()
+                    }),
+                    docs: Some(Docs::Rules(Rules))
+                }
+            },
+            InvalidSyntax::UnnamedTeam { row, col, line } => Msg {
+                msg: format!("Team name at line {row} is empty.").into_boxed_str(),
+                error: None,
+                line: Some(OffendingLine {
+                    file,
+                    severity: Severity::Error,
+                    line: Some(&**line),
+                    row: *row,
+                    col: Some(col.clone())
+                }),
+                related: None,
+                hint: Some("Give that team a name!".into()),
+                docs: Some(Docs::Rules(Rules))
+            },
+            InvalidSyntax::CannotParsePoints { row, col, round, line, team, error } => {
+                let span = &line[col.clone()];
+                let col_start = col.start;
+                Msg {
+                    msg: format!("Unable to parse points for team \"{team}\" for round {round} at line {row}, collumn {col_start} \"{span}\"").into_boxed_str(),
+                    error: Some(error),
+                    line: Some(OffendingLine {
+                        file,
+                        severity: Severity::Error,
+                        line: Some(&**line),
+                        row: *row,
+                        col: Some(col.clone())
+                    }),
+                    related: None,
+                    hint: Some(match error
+                    {
+                        InvalidExpression::InvalidNumber { .. } => format!("\"{span}\" must be a valid number, or \"{ABSENT}\" if the team didn't participate in the round."),
+                        _ => "Points can also be calculated with '+', '-', '*', '/' and parentheses, like \"7+2\" or \"(10-1)/2\", as long as there are no spaces.".into()
+                    }.into_boxed_str()),
+                    docs: Some(Docs::Rules(Rules))
+                }
+            },
+            InvalidSyntax::DuplicateJoker { row, col, line, team, round, first_round } => Msg {
+                msg: format!("The team \"{team}\" played their joker in both round {first_round} and round {round} at line {row}, collumn {col_start}.", col_start = col.start).into_boxed_str(),
+                error: None,
+                line: Some(OffendingLine {
+                    file,
+                    severity: Severity::Error,
+                    line: Some(&**line),
+                    row: *row,
+                    col: Some(col.clone())
+                }),
+                related: None,
+                hint: Some(format!("Each team can only play one joker. Remove the '{JOKER}' from one of the rounds.").into_boxed_str()),
+                docs: Some(Docs::Rules(Rules))
+            },
+            InvalidSyntax::UnknownDirective { row, col, line } => {
+                let span = line[col.clone()].trim();
+                let directives = Directive::VARIANTS.map(|directive| format!("\"{DIRECTIVE}{directive}\"")).join(", ");
+                Msg {
+                    msg: format!("Unknown directive \"{DIRECTIVE}{span}\" at line {row}.").into_boxed_str(),
+                    error: None,
+                    line: Some(OffendingLine {
+                        file,
+                        severity: Severity::Error,
+                        line: Some(&**line),
+                        row: *row,
+                        col: Some(col.clone())
+                    }),
+                    related: None,
+                    hint: Some(format!("Lines starting with '{DIRECTIVE}' must be one of: {directives}. Write '{ESCAPE}{DIRECTIVE}' if the '{DIRECTIVE}' is part of a team name.").into_boxed_str()),
+                    docs: Some(Docs::Rules(Rules))
+                }
+            },
+            InvalidSyntax::DuplicateDirective { row, line, directive, first_row } => Msg {
+                msg: format!("\"{DIRECTIVE}{directive}\" is given more than once, at line {first_row} and line {row}.").into_boxed_str(),
                 error: None,
                 line: Some(OffendingLine {
                     file,
@@ -58,11 +204,12 @@ impl InvalidSyntax
                     row: *row,
                     col: None
                 }),
-                hint: Some("Use a ':' to separate the team name and their points for each round.".into()),
+                related: None,
+                hint: Some("Remove one of them, or combine them into one line.".into()),
                 docs: Some(Docs::Rules(Rules))
             },
-            InvalidSyntax::UnnamedTeam { row, col, line } => Msg {
-                msg: format!("Team name at line {row} is empty.").into_boxed_str(),
+            InvalidSyntax::InvalidRoundName { row, col, line, name } => Msg {
+                msg: format!("Invalid round name \"{name}\" at line {row}.").into_boxed_str(),
                 error: None,
                 line: Some(OffendingLine {
                     file,
@@ -71,15 +218,29 @@ impl InvalidSyntax
                     row: *row,
                     col: Some(col.clone())
                 }),
-                hint: Some("Give that team a name!".into()),
+                related: None,
+                hint: Some("Round names can't start with a number, or contain \"..\", since they would be mistaken for round numbers on the commandline.".into()),
                 docs: Some(Docs::Rules(Rules))
             },
-            InvalidSyntax::CannotParsePoints { row, col, round, line, team, error } => {
-                let span = &line[col.clone()];
-                let col_start = col.start;
+            InvalidSyntax::DuplicateRoundName { row, col, line, name } => Msg {
+                msg: format!("The round name \"{name}\" is used more than once at line {row}.").into_boxed_str(),
+                error: None,
+                line: Some(OffendingLine {
+                    file,
+                    severity: Severity::Error,
+                    line: Some(&**line),
+                    row: *row,
+                    col: Some(col.clone())
+                }),
+                related: None,
+                hint: Some("Each round needs its own name.".into()),
+                docs: Some(Docs::Rules(Rules))
+            },
+            InvalidSyntax::UnknownTieBreak { row, col, line, name } => {
+                let tie_breaks = TieBreak::VARIANTS.map(|tie_break| format!("\"{tie_break}\"")).join(", ");
                 Msg {
-                    msg: format!("Unable to parse points for team \"{team}\" for round {round} at line {row}, collumn {col_start} \"{span}\"").into_boxed_str(),
-                    error: Some(error),
+                    msg: format!("Unknown tie-break \"{name}\" at line {row}.").into_boxed_str(),
+                    error: None,
                     line: Some(OffendingLine {
                         file,
                         severity: Severity::Error,
@@ -87,10 +248,45 @@ impl InvalidSyntax
                         row: *row,
                         col: Some(col.clone())
                     }),
-                    hint: Some(format!("\"{span}\" must be a valid number.").into_boxed_str()),
+                    related: None,
+                    hint: Some(format!("Tie-breaks must be one of: {tie_breaks}.").into_boxed_str()),
                     docs: Some(Docs::Rules(Rules))
                 }
             },
+            InvalidSyntax::InvalidWeight { row, col, line, weight } => Msg {
+                msg: format!("Invalid weight \"{weight}\" at line {row}.").into_boxed_str(),
+                error: None,
+                line: Some(OffendingLine {
+                    file,
+                    severity: Severity::Error,
+                    line: Some(&**line),
+                    row: *row,
+                    col: Some(col.clone())
+                }),
+                related: None,
+                hint: Some("A weight must be a number that isn't negative, like \"2\" or \"1.5\". Leave it out for a round that counts normally.".into()),
+                docs: Some(Docs::Rules(Rules))
+            },
+            InvalidSyntax::ConflictingPoints { row, col, line, team, round, first_file, first_row, first_col, first_line } => Msg {
+                msg: format!("The team \"{team}\" got points for round {round} both in \"{first_file}\" at line {first_row} and in \"{file}\" at line {row}.").into_boxed_str(),
+                error: None,
+                line: Some(OffendingLine {
+                    file,
+                    severity: Severity::Error,
+                    line: Some(&**line),
+                    row: *row,
+                    col: Some(col.clone())
+                }),
+                related: Some(OffendingLine {
+                    file: first_file,
+                    severity: Severity::Error,
+                    line: Some(&**first_line),
+                    row: *first_row,
+                    col: Some(first_col.clone())
+                }),
+                hint: Some("When reading more than one file, a round with the same name in two of them is the same round. Remove the points from one of the files, or give the rounds different names.".into()),
+                docs: Some(Docs::Rules(Rules))
+            }
         }
     }
 }
@@ -100,7 +296,7 @@ impl From<InvalidSyntax> for InvalidRead
     fn from(error: InvalidSyntax) -> Self
     {
         InvalidRead::InvalidSyntax {
-            error
+            errors: [error].into()
         }    
     }
 }
\ No newline at end of file
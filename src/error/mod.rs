@@ -1,20 +1,24 @@
 use core::fmt::Display;
-use std::{borrow::Cow, path::Path};
-
-use crate::help::Help;
+use crate::{help::Help, record::Source};
 
 moddef::moddef!(
     flat(pub) mod {
         arg_error,
+        exit_code,
         expected_arg,
         insufficient_data,
         invalid_arg,
         invalid_call,
+        invalid_entry,
+        invalid_expression,
         invalid_flag,
         invalid_io,
+        invalid_number,
         invalid_read,
         invalid_syntax,
         msg,
+        report,
+        warning,
     }
 );
 
@@ -22,7 +26,7 @@ moddef::moddef!(
 pub enum Error
 {
     InvalidRead {
-        file: Cow<'static, Path>,
+        file: Source,
         error: InvalidRead
     },
     InvalidCall {
@@ -33,7 +37,15 @@ pub enum Error
     InsufficientData {
         error: InsufficientData
     },
+    InvalidEntry {
+        exe: &'static str,
+        error: InvalidEntry
+    },
     NoExecutable,
+    /// Points that add up to more than can be kept exactly.
+    PointsTooLarge {
+        team: Box<str>
+    },
     ShowHelp {
         help: Help
     }
@@ -53,21 +65,31 @@ impl Error
         format!("{no}{suffix}").into_boxed_str()
     }
 
-    fn msg(&self) -> Msg<'_>
+    fn report(&self) -> Report<'_>
     {
         match self
         {
-            Error::InvalidRead { file, error } => error.msg(file),
-            Error::InvalidCall { exe, no, error } => error.msg(exe, *no),
-            Error::InsufficientData { error } => error.msg(),
+            Error::InvalidRead { file, error } => error.report(file),
+            Error::InvalidCall { exe, no, error } => error.msg(exe, *no).into(),
+            Error::InsufficientData { error } => error.msg().into(),
+            Error::InvalidEntry { exe, error } => error.msg(exe).into(),
             Error::NoExecutable => Msg {
                 msg: "You somehow managed to run this binary without even a 0th argument.".into(),
                 error: None,
                 line: None,
+                related: None,
                 hint: Some("Not sure how you did it, but don't do that.".into()),
                 docs: None
-            },
-            Error::ShowHelp { help } => help.msg(),
+            }.into(),
+            Error::PointsTooLarge { team } => Msg {
+                msg: format!("The points of \"{team}\" are too large to add up exactly.").into_boxed_str(),
+                error: None,
+                line: None,
+                related: None,
+                hint: Some("Check that none of their points have too many digits, or are divided by something that doesn't go evenly into the others.".into()),
+                docs: None
+            }.into(),
+            Error::ShowHelp { help } => help.msg().into(),
         }
     }
 }
@@ -76,6 +98,6 @@ impl Display for Error
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        self.msg().fmt(f)
+        self.report().fmt(f)
     }
 }
\ No newline at end of file
@@ -1,18 +1,16 @@
 use core::{error::Error, fmt::Display, ops::Range};
-use std::path::Path;
-
-use crate::{help::Docs, style};
+use crate::{help::Docs, record::Source, style};
 
 #[derive(Clone, Copy)]
 pub enum Severity
 {
-    //Warning,
+    Warning,
     Error
 }
 
 pub struct OffendingLine<'a>
 {
-    pub file: &'a Path,
+    pub file: &'a Source,
     pub severity: Severity,
     pub line: Option<&'a str>,
     pub row: usize,
@@ -26,7 +24,6 @@ impl Display for OffendingLine<'_>
         let Self { file, severity, line, row, col } = self;
 
         let in_file = {
-            let file = file.display();
             let at_col = match col.as_ref().map(|col| col.start)
             {
                 None => "".into(),
@@ -91,6 +88,8 @@ pub struct Msg<'a>
     pub msg: Box<str>,
     pub error: Option<&'a dyn Error>,
     pub line: Option<OffendingLine<'a>>,
+    /// Another place that has to do with the error, like where something was first given.
+    pub related: Option<OffendingLine<'a>>,
     pub hint: Option<Box<str>>,
     pub docs: Option<Docs<'a>>
 }
@@ -99,7 +98,7 @@ impl Display for Msg<'_>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        let Self { msg, error, line, hint, docs } = self;
+        let Self { msg, error, line, related, hint, docs } = self;
 
         let msg = style::info(format!("{msg}"));
         write!(f, "{msg}")?;
@@ -113,6 +112,10 @@ impl Display for Msg<'_>
         {
             write!(f, "\n\n{line}")?;
         }
+        if let Some(related) = related
+        {
+            write!(f, "\n\n{related}")?;
+        }
 
         if let Some(hint) = hint
         {
@@ -0,0 +1,78 @@
+use core::fmt::Display;
+
+use crate::{help::Docs, style};
+
+use super::{Msg, Severity};
+
+/// One or more messages, printed one after another.
+pub struct Report<'a>
+{
+    pub msgs: Vec<Msg<'a>>,
+    pub docs: Option<Docs<'a>>,
+    pub summary: Option<(Severity, Box<str>)>
+}
+
+impl<'a> Report<'a>
+{
+    pub fn new(msgs: impl IntoIterator<Item = Msg<'a>>, summary: Option<(Severity, Box<str>)>) -> Self
+    {
+        let mut msgs = msgs.into_iter()
+            .collect::<Vec<_>>();
+
+        // The docs are usually the same for every message, so they're only shown once at the end
+        let docs = if msgs.len() > 1
+        {
+            msgs.iter_mut()
+                .fold(None, |docs, msg| docs.or(msg.docs.take()))
+        }
+        else
+        {
+            None
+        };
+
+        Self {
+            msgs,
+            docs,
+            summary
+        }
+    }
+}
+
+impl<'a> From<Msg<'a>> for Report<'a>
+{
+    fn from(msg: Msg<'a>) -> Self
+    {
+        Self::new([msg], None)
+    }
+}
+
+impl Display for Report<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let Self { msgs, docs, summary } = self;
+
+        let mut first = true;
+        for msg in msgs
+        {
+            if !first
+            {
+                write!(f, "\n\n\n")?;
+            }
+            first = false;
+            write!(f, "{msg}")?;
+        }
+
+        if let Some(docs) = docs
+        {
+            write!(f, "\n\n{docs}")?;
+        }
+
+        if let Some((severity, summary)) = summary
+        {
+            write!(f, "\n\n{summary}", summary = style::severity(&**summary, *severity))?;
+        }
+
+        Ok(())
+    }
+}
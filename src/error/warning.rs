@@ -0,0 +1,112 @@
+use core::ops::Range;
+use crate::{rational::Rational, record::Source};
+
+use super::{Msg, OffendingLine, Severity};
+
+/// Something in the poengsum-file that is allowed, but probably a mistake.
+#[derive(Debug)]
+pub enum Warning
+{
+    DuplicateTeam {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        team: Box<str>,
+        first_row: usize
+    },
+    SimilarTeams {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        team: Box<str>,
+        other: Box<str>,
+        other_row: usize
+    },
+    NegativePoints {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        team: Box<str>,
+        round: usize,
+        points: Rational
+    },
+    ExtraRounds {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        team: Box<str>,
+        rounds: usize,
+        others: usize
+    },
+    FarAbove {
+        row: usize,
+        col: Range<usize>,
+        line: Box<str>,
+        team: Box<str>,
+        round: usize,
+        points: Rational,
+        median: Rational
+    }
+}
+
+impl Warning
+{
+    /// With "--strict", the same message is shown as an error instead.
+    pub fn msg<'a>(&'a self, file: &'a Source, severity: Severity) -> Msg<'a>
+    {
+        let offending_line = |row: &usize, col: &Range<usize>, line: &'a str| OffendingLine {
+            file,
+            severity,
+            line: Some(line),
+            row: *row,
+            col: Some(col.clone())
+        };
+        match self
+        {
+            Warning::DuplicateTeam { row, col, line, team, first_row } => Msg {
+                msg: format!("The team \"{team}\" is listed more than once, at line {first_row} and line {row}.").into_boxed_str(),
+                error: None,
+                line: Some(offending_line(row, col, line)),
+                related: None,
+                hint: Some("Write all the points of a team on the same line. If they are different teams, give them different names.".into()),
+                docs: None
+            },
+            Warning::SimilarTeams { row, col, line, team, other, other_row } => Msg {
+                msg: format!("The team \"{team}\" at line {row} has almost the same name as \"{other}\" at line {other_row}.").into_boxed_str(),
+                error: None,
+                line: Some(offending_line(row, col, line)),
+                related: None,
+                hint: Some("The names only differ in upper and lower case, or in spaces. If it's the same team, write all its points on the same line.".into()),
+                docs: None
+            },
+            Warning::NegativePoints { row, col, line, team, round, points } => Msg {
+                msg: format!("The team \"{team}\" got negative points ({points}) in round {round} at line {row}.", points = *points).into_boxed_str(),
+                error: None,
+                line: Some(offending_line(row, col, line)),
+                related: None,
+                hint: Some("Check that the '-' isn't a typo. Write \"-\" on its own if the team didn't participate in the round.".into()),
+                docs: None
+            },
+            Warning::ExtraRounds { row, col, line, team, rounds, others } => Msg {
+                msg: format!("The team \"{team}\" has points for {rounds} rounds at line {row}, but no other team has more than {others}.").into_boxed_str(),
+                error: None,
+                line: Some(offending_line(row, col, line)),
+                related: None,
+                hint: Some("Check that there isn't a space inside one of the numbers, or that the points of another team aren't missing.".into()),
+                docs: None
+            },
+            Warning::FarAbove { row, col, line, team, round, points, median } => Msg {
+                msg: format!(
+                    "The team \"{team}\" got {points} points in round {round} at line {row}, far above the {median} points that the others usually got.",
+                    points = *points,
+                    median = *median
+                ).into_boxed_str(),
+                error: None,
+                line: Some(offending_line(row, col, line)),
+                related: None,
+                hint: Some("Check for a typo, like a missing decimal point.".into()),
+                docs: None
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use core::{fmt::Display, ops::Deref};
 
-use crate::{error::InvalidArg, help::CallExample};
+use crate::{command::{Aggregate, CommandKind, PLACEMENT_POINTS}, error::InvalidArg, help::CallExample, output::{Format, Notation}, rational::Rational, record::{JOKER, JOKER_FACTOR, STDIN}, round::{Count, WEIGHT_SEPARATOR}, scoring::{Ranking, TieBreak, TIE_BREAK_SEPARATOR}};
 
 use super::{Flag, FlagOption};
 
@@ -10,7 +10,26 @@ pub enum FlagKind
 {
     Help,
     File,
-    Rev
+    Rev,
+    Table,
+    Output,
+    Long,
+    Strict,
+    Watch,
+    Reveal,
+    Delay,
+    Podium,
+    New,
+    Aggregate,
+    Best,
+    TieBreak,
+    Ranking,
+    Weights,
+    Count,
+    Decimals,
+    Fractions,
+    Since,
+    Chart
 }
 
 impl FlagKind
@@ -21,12 +40,50 @@ impl FlagKind
         {
             Self::Help => "help",
             Self::File => "file",
-            Self::Rev => "rev"
+            Self::Rev => "rev",
+            Self::Table => "table",
+            Self::Output => "output",
+            Self::Long => "long",
+            Self::Strict => "strict",
+            Self::Watch => "watch",
+            Self::Reveal => "reveal",
+            Self::Delay => "delay",
+            Self::Podium => "podium",
+            Self::New => "new",
+            Self::Aggregate => "aggregate",
+            Self::Best => "best",
+            Self::TieBreak => "tie-break",
+            Self::Ranking => "ranking",
+            Self::Weights => "weights",
+            Self::Count => "count",
+            Self::Decimals => "decimals",
+            Self::Fractions => "fractions",
+            Self::Since => "since",
+            Self::Chart => "chart"
         }
     }
     pub fn option_short(self) -> char
     {
-        self.option().chars().next().unwrap()
+        match self
+        {
+            // "-r" is already taken by "--rev"
+            Self::Reveal => 'R',
+            // "-t" is already taken by "--table"
+            Self::TieBreak => 'T',
+            // "-r" and "-R" are already taken by "--rev" and "--reveal"
+            Self::Ranking => 'k',
+            // "-w" is already taken by "--watch"
+            Self::Weights => 'W',
+            // "-d" is already taken by "--delay"
+            Self::Decimals => 'D',
+            // "-f" is already taken by "--file"
+            Self::Fractions => 'F',
+            // "-s" is already taken by "--strict"
+            Self::Since => 'S',
+            // "-c" is already taken by "--count"
+            Self::Chart => 'C',
+            _ => self.option().chars().next().unwrap()
+        }
     }
     pub fn help(self, exe: &'static str) -> String
     {
@@ -38,10 +95,87 @@ impl FlagKind
             FlagKind::File => {
                 let default_file = crate::default_file_path().display();
                 format!(
-                    "By default, the file that the score is read from is \"{default_file}\", but you can use a different file by setting the \"--file\" flag, followed by a path."
+                    "By default, the file that the score is read from is \"{default_file}\", but you can use a different file by setting the \"--file\" flag, followed by a path. With \"{STDIN}\" as the path, it's read from standard input instead. Given more than once, the rounds of each file come after the rounds of the files before it, with teams matched by name."
+                )
+            },
+            FlagKind::Rev => "Reverses the direction in which the teams' score is shown in the scoreboard.".into(),
+            FlagKind::Table => format!(
+                "Shows the scoreboard as a table, with a column for the points each team got in each round, in the order they were played. This also works with the \"{markdown}\" and \"{html}\" output formats.",
+                markdown = Format::Markdown,
+                html = Format::Html
+            ),
+            FlagKind::Output => {
+                let formats = Format::VARIANTS.map(Format::name).join(", ");
+                format!(
+                    "By default, the scoreboard is shown as text for the terminal, but you can choose a different output format by setting the \"--output\" flag, followed by one of: {formats}."
                 )
             },
-            FlagKind::Rev => "Reverses the direction in which the teams' score is shown in the scoreboard.".into()
+            FlagKind::Long => format!(
+                "By default, the \"{csv}\" and \"{tsv}\" output formats have one row per team, with a column for each round. With \"--long\" there is instead one row per team per round.",
+                csv = Format::Csv,
+                tsv = Format::Tsv
+            ),
+            FlagKind::Strict => "Things in the poengsum-file that are probably mistakes, like a team listed twice or negative points, are shown as warnings above the scoreboard. With \"--strict\" they are errors instead, and the scoreboard isn't shown.".into(),
+            FlagKind::Watch => "Keeps the scoreboard open, and redraws it every time the poengsum-file is saved. If the file can't be read, the last scoreboard that worked is kept on screen, with the error below it. Press Ctrl+C to stop. It can't be combined with \"--reveal\", \"--delay\" or \"--podium\".".into(),
+            FlagKind::Reveal => "Reveals the scoreboard one place at a time, starting with the last place, for reading the results out loud. Press Enter to reveal the next place. Teams that share a place are revealed together.".into(),
+            FlagKind::Delay => "With \"--delay\", followed by a number of seconds, the next place is revealed automatically after waiting that long, instead of waiting for Enter. This also turns on \"--reveal\".".into(),
+            FlagKind::Podium => format!("Holds back the top {PODIUM} places until the rest have been revealed, then reveals them all at once when Enter is pressed. This also turns on \"--reveal\".", PODIUM = crate::reveal::PODIUM),
+            FlagKind::New => format!(
+                "By default, \"{add_round}\" refuses to add points for a team that isn't in the poengsum-file, in case the name is misspelled. With \"--new\", the team is added to the end of the file instead, unless its name is close to a team in the file and it was read from the input, where it can't be asked about.",
+                add_round = CommandKind::AddRound
+            ),
+            FlagKind::Aggregate => format!(
+                "Chooses what each night is worth in the standings of \"{season}\". With \"{total}\", which is the default, it's the points the team got that night. With \"{placement}\", it's points for the place they got, {placement_points} for the first {places} places.",
+                season = CommandKind::Season,
+                total = Aggregate::Total,
+                placement = Aggregate::Placement,
+                placement_points = PLACEMENT_POINTS.map(|points| points.to_string()).join("-"),
+                places = PLACEMENT_POINTS.len()
+            ),
+            FlagKind::Best => format!(
+                "Only counts the nights where each team did best in the standings of \"{season}\", so that missing a night or two isn't as costly.",
+                season = CommandKind::Season
+            ),
+            FlagKind::TieBreak => {
+                let tie_breaks = TieBreak::VARIANTS.map(TieBreak::name).join(", ");
+                format!(
+                    "By default, teams with the same points share the place. With \"--tie-break\", followed by rules separated by '{TIE_BREAK_SEPARATOR}', the first rule that tells the teams apart decides who is placed ahead, and is shown next to their points. The rules are: {tie_breaks}. This overrides any \"@tie-break\" line in the poengsum-file."
+                )
+            },
+            FlagKind::Ranking => format!(
+                "Chooses how places are numbered when teams share one. With \"{standard}\", which is the default, two teams tied for 2nd are followed by 4th. With \"{dense}\", they are followed by 3rd. With \"{ordinal}\", every team gets its own place. With \"{fractional}\", they both get 2.5, the average of the places they take up.",
+                standard = Ranking::Standard,
+                dense = Ranking::Dense,
+                ordinal = Ranking::Ordinal,
+                fractional = Ranking::Fractional
+            ),
+            FlagKind::Weights => format!(
+                "Multiplies the points in each round by a weight, like \"1{WEIGHT_SEPARATOR}1{WEIGHT_SEPARATOR}2\" for a final round that counts double. A round that isn't given a weight counts normally. This overrides any \"@weights\" line in the poengsum-file, and with \"{season}\" it weighs the nights instead. A team's points written with '{JOKER}' after them, like \"5{JOKER}\", are their joker round, and are multiplied by {JOKER_FACTOR} on top of the weight.",
+                season = CommandKind::Season
+            ),
+            FlagKind::Count => format!(
+                "Chooses which rounds count towards each team's total. With \"{all}\", which is the default, every round counts. With \"{best}\", followed by a number, like \"{best_5}\", only that many of the team's best rounds count. With \"{drop}\", followed by a number, like \"{drop_1}\", that many of the team's worst rounds don't count, starting with the rounds they didn't participate in. With \"{present}\", only the rounds they participated in count. The rounds that don't count are shown in parentheses in the table.",
+                all = Count::All,
+                best = Count::BEST,
+                best_5 = Count::Best(5),
+                drop = Count::DROP,
+                drop_1 = Count::Drop(1),
+                present = Count::Present
+            ),
+            FlagKind::Decimals => format!(
+                "By default, points are shown exactly, like 60.3, and points that can't be written exactly as a decimal number, like a third, are rounded to {decimals} decimal places. With \"--decimals\", followed by a number up to {max_decimals}, all points are rounded to that many decimal places instead. The points are always added up exactly, only what is shown is rounded.",
+                decimals = Rational::DECIMALS,
+                max_decimals = Notation::MAX_DECIMALS
+            ),
+            FlagKind::Fractions => format!(
+                "Shows points as a whole number and a fraction, like \"60 1/3\", instead of as a decimal number. The \"{json}\" output format always has decimal numbers.",
+                json = Format::Json
+            ),
+            FlagKind::Since => "By default, the climb is how many places each team moved since the round before. With \"--since\", followed by a round number or name, it's how far they moved since the standings after that round instead, like since halftime. It can also be followed by a selection of rounds, like \"2..4\", written the same way as the rounds after the flags, and given more than once to add more rounds to it.".into(),
+            FlagKind::Chart => format!(
+                "Shows how the place of each team changed from round to round as a chart in the terminal, with a line in its own colour for each team. This only works with the \"{text}\" output format.",
+                text = Format::Text
+            )
         }
     }
     pub fn examples(self, exe: &'static str) -> Vec<CallExample>
@@ -79,6 +213,184 @@ impl FlagKind
                     args: vec!["--rev".into()],
                     effect: Some("Displays the leaderboard in reverse.".into())
                 }
+            ],
+            Self::Table => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--table".into()],
+                    effect: Some("Shows the points for each round in a table.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec!["--table".into(), "2".into(), "1".into()],
+                    effect: Some("Shows the points for round 2, then round 1, in a table.".into())
+                }
+            ],
+            Self::Output => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--output".into(), Format::Json.name().into()],
+                    effect: Some("Outputs the scoreboard as JSON.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec!["--output".into(), Format::Csv.name().into()],
+                    effect: Some("Outputs the scoreboard as comma-separated values.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec!["--output".into(), Format::Tsv.name().into()],
+                    effect: Some("Outputs the scoreboard as tab-separated values.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec!["--output".into(), Format::Markdown.name().into()],
+                    effect: Some("Outputs the scoreboard as a Markdown table.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec!["--output".into(), Format::Html.name().into(), "--table".into()],
+                    effect: Some("Outputs a web page with the points for each round.".into())
+                }
+            ],
+            Self::Long => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--output".into(), Format::Csv.name().into(), "--long".into()],
+                    effect: Some("Outputs one row for each round of each team.".into())
+                }
+            ],
+            Self::Strict => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--strict".into()],
+                    effect: Some("Refuses to show the scoreboard if there are any warnings.".into())
+                }
+            ],
+            Self::Watch => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--watch".into(), "--table".into()],
+                    effect: Some("Shows the points for each round, and updates them as they are typed into the poengsum-file.".into())
+                }
+            ],
+            Self::Reveal => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--reveal".into()],
+                    effect: Some("Reveals one place each time Enter is pressed.".into())
+                }
+            ],
+            Self::Delay => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--reveal".into(), "--delay".into(), "5".into()],
+                    effect: Some("Reveals one place every 5 seconds.".into())
+                }
+            ],
+            Self::Podium => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--reveal".into(), "--delay".into(), "2".into(), "--podium".into()],
+                    effect: Some("Reveals one place every 2 seconds, and then waits for Enter before the podium.".into())
+                }
+            ],
+            Self::New => vec![
+                CallExample {
+                    exe,
+                    args: vec![CommandKind::AddRound.name().into(), "--new".into(), "Quizzbuzz=5".into(), "Late Arrivals=3".into()],
+                    effect: Some("Adds a round, and adds the team \"Late Arrivals\" if it isn't in the poengsum-file yet.".into())
+                }
+            ],
+            Self::Aggregate => vec![
+                CallExample {
+                    exe,
+                    args: vec![CommandKind::Season.name().into(), "--aggregate".into(), Aggregate::Placement.name().into()],
+                    effect: Some("Gives each team points for the place they got on each night.".into())
+                }
+            ],
+            Self::Best => vec![
+                CallExample {
+                    exe,
+                    args: vec![CommandKind::Season.name().into(), "--best".into(), "5".into()],
+                    effect: Some("Only counts the 5 best nights of each team.".into())
+                }
+            ],
+            Self::TieBreak => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--tie-break".into(), format!("{last_round}{TIE_BREAK_SEPARATOR}{countback}", last_round = TieBreak::LastRound, countback = TieBreak::Countback).into_boxed_str()],
+                    effect: Some("Breaks ties by the points in the final round, and then by countback.".into())
+                }
+            ],
+            Self::Ranking => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--ranking".into(), Ranking::Dense.name().into()],
+                    effect: Some("Numbers the places without gaps after teams that share one.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec![CommandKind::Season.name().into(), "--aggregate".into(), Aggregate::Placement.name().into(), "--ranking".into(), Ranking::Fractional.name().into()],
+                    effect: Some("Gives teams that share a place the average of the points for the places they take up.".into())
+                }
+            ],
+            Self::Weights => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--weights".into(), format!("1{WEIGHT_SEPARATOR}1{WEIGHT_SEPARATOR}2").into_boxed_str(), "--table".into()],
+                    effect: Some("Counts the points in round 3 double, and shows the points both before and after.".into())
+                }
+            ],
+            Self::Count => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--count".into(), Count::Best(5).to_string().into_boxed_str(), "--table".into()],
+                    effect: Some("Only counts the 5 best rounds of each team.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec!["--count".into(), Count::Drop(1).to_string().into_boxed_str()],
+                    effect: Some("Doesn't count the worst round of each team.".into())
+                }
+            ],
+            Self::Decimals => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--decimals".into(), "1".into()],
+                    effect: Some("Shows all points with one decimal place, like 60.0 and 42.5.".into())
+                }
+            ],
+            Self::Fractions => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--fractions".into(), "--table".into()],
+                    effect: Some("Shows a third of a point as 1/3, instead of 0.33.".into())
+                }
+            ],
+            Self::Since => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--since".into(), "1".into()],
+                    effect: Some("Shows how far each team has moved since the first round.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec!["--since".into(), "2..3".into()],
+                    effect: Some("Shows how far each team has moved compared to the standings of only round 2 and 3.".into())
+                }
+            ],
+            Self::Chart => vec![
+                CallExample {
+                    exe,
+                    args: vec!["--chart".into()],
+                    effect: Some("Draws the place of each team after each round.".into())
+                },
+                CallExample {
+                    exe,
+                    args: vec![CommandKind::Season.name().into(), "--chart".into()],
+                    effect: Some("Draws the place of each team in the season standings after each night.".into())
+                }
             ]
         }
     }
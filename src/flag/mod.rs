@@ -1,6 +1,6 @@
-use std::path::PathBuf;
+use std::{path::PathBuf, time::Duration};
 
-use crate::help::Help;
+use crate::{command::Aggregate, help::Help, output::Format, round::{Count, Round, Weights}, scoring::{Ranking, TieBreak}};
 
 moddef::moddef!(
     flat(pub) mod {
@@ -14,12 +14,31 @@ pub enum Flag
     Help(Help),
     File(PathBuf),
     Rev,
+    Table,
+    Output(Format),
+    Long,
+    Strict,
+    Watch,
+    Reveal,
+    Delay(Duration),
+    Podium,
+    New,
+    Aggregate(Aggregate),
+    Best(usize),
+    TieBreak(Box<[TieBreak]>),
+    Ranking(Ranking),
+    Weights(Weights),
+    Count(Count),
+    Decimals(usize),
+    Fractions,
+    Since(Round),
+    Chart
 }
 
 impl Flag
 {
-    pub const VARIANT_COUNT: usize = 3; //core::mem::variant_count::<FlagKind>(); TODO use this instead when stable
-    pub const VARIANTS: [FlagKind; Self::VARIANT_COUNT] = [FlagKind::Help, FlagKind::File, FlagKind::Rev];
+    pub const VARIANT_COUNT: usize = 22; //core::mem::variant_count::<FlagKind>(); TODO use this instead when stable
+    pub const VARIANTS: [FlagKind; Self::VARIANT_COUNT] = [FlagKind::Help, FlagKind::File, FlagKind::Rev, FlagKind::Table, FlagKind::Output, FlagKind::Long, FlagKind::Strict, FlagKind::Watch, FlagKind::Reveal, FlagKind::Delay, FlagKind::Podium, FlagKind::New, FlagKind::Aggregate, FlagKind::Best, FlagKind::TieBreak, FlagKind::Ranking, FlagKind::Weights, FlagKind::Count, FlagKind::Decimals, FlagKind::Fractions, FlagKind::Since, FlagKind::Chart];
 
     pub fn kind(&self) -> FlagKind
     {
@@ -27,7 +46,26 @@ impl Flag
         {
             Self::Help(..) => FlagKind::Help,
             Self::File(..) => FlagKind::File,
-            Self::Rev => FlagKind::Rev
+            Self::Rev => FlagKind::Rev,
+            Self::Table => FlagKind::Table,
+            Self::Output(..) => FlagKind::Output,
+            Self::Long => FlagKind::Long,
+            Self::Strict => FlagKind::Strict,
+            Self::Watch => FlagKind::Watch,
+            Self::Reveal => FlagKind::Reveal,
+            Self::Delay(..) => FlagKind::Delay,
+            Self::Podium => FlagKind::Podium,
+            Self::New => FlagKind::New,
+            Self::Aggregate(..) => FlagKind::Aggregate,
+            Self::Best(..) => FlagKind::Best,
+            Self::TieBreak(..) => FlagKind::TieBreak,
+            Self::Ranking(..) => FlagKind::Ranking,
+            Self::Weights(..) => FlagKind::Weights,
+            Self::Count(..) => FlagKind::Count,
+            Self::Decimals(..) => FlagKind::Decimals,
+            Self::Fractions => FlagKind::Fractions,
+            Self::Since(..) => FlagKind::Since,
+            Self::Chart => FlagKind::Chart
         }
     }
 }
\ No newline at end of file
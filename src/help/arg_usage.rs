@@ -10,7 +10,7 @@ pub struct ArgUsage {
 
 impl ArgUsage
 {
-    pub fn examples(&self) -> [CallExample; 4]
+    pub fn examples(&self) -> [CallExample; 5]
     {
         let Self { exe } = self;
         
@@ -34,6 +34,11 @@ impl ArgUsage
                 exe,
                 args: vec!["1".into(), "3".into(), "5..7".into(), "9".into()],
                 effect: Some("Ranges can be used to include a contiguous sequence of rounds.".into())
+            },
+            CallExample {
+                exe,
+                args: vec!["Music..Geography".into()],
+                effect: Some("Named rounds can be used instead of numbers.".into())
             }
         ]
     }
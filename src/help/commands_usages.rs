@@ -0,0 +1,30 @@
+use core::fmt::Display;
+
+use crate::{command::CommandKind, style};
+
+pub struct CommandsUsages
+{
+    pub exe: &'static str
+}
+
+impl Display for CommandsUsages
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        if CommandKind::VARIANT_COUNT == 0
+        {
+            return Ok(())
+        }
+        write!(f, "{header}", header = style::header("Commands:"))?;
+
+        for command in CommandKind::VARIANTS
+        {
+            for example in command.examples(self.exe)
+            {
+                write!(f, "\n\t{example}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
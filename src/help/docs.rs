@@ -1,10 +1,11 @@
 use core::fmt::Display;
 
-use super::{ArgUsage, FlagUsage, FlagsUsages, Help, Rules};
+use super::{ArgUsage, CommandsUsages, FlagUsage, FlagsUsages, Help, Rules};
 
 pub enum Docs<'a>
 {
     ArgUsage(ArgUsage),
+    CommandsUsages(CommandsUsages),
     Help(&'a Help),
     //FlagHelp(FlagHelp),
     FlagsUsages(FlagsUsages),
@@ -19,6 +20,7 @@ impl Display for Docs<'_>
         match self
         {
             Docs::ArgUsage(arg_usage) => arg_usage.fmt(f),
+            Docs::CommandsUsages(commands_usages) => commands_usages.fmt(f),
             Docs::Help(help) => help.fmt(f),
             //Docs::FlagHelp(flag_help) => flag_help.fmt(f),
             Docs::FlagsUsages(flags_usages) => flags_usages.fmt(f),
@@ -0,0 +1,21 @@
+use core::fmt::Display;
+
+use crate::{error::ExitCode, style};
+
+pub struct ExitCodes;
+
+impl Display for ExitCodes
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        write!(f, "{header}", header = style::header("Exit codes:"))?;
+
+        for code in ExitCode::VARIANTS
+        {
+            let description = style::info(code.description());
+            write!(f, "\n\t{code} {description}", code = style::arg(code.code().to_string()))?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,13 +1,14 @@
 use core::fmt::Display;
 
-use crate::style;
+use crate::{record::COMMENT, style};
 
 use super::ALIGN;
 
 pub struct LineExample
 {
-    pub team: Box<str>,
+    pub team: Option<Box<str>>,
     pub points: Box<str>,
+    pub comment: Option<Box<str>>,
     pub effect: Option<Box<str>>,
     pub row: usize
 }
@@ -16,14 +17,28 @@ impl Display for LineExample
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        let Self {team, points, effect, row} = self;
+        let Self {team, points, comment, effect, row} = self;
         let mut align = ALIGN;
 
-        let team = style::team_line(format!("{team}:"));
-        let points = style::points_line(&**points);
+        write!(f, "{row_l}", row_l = style::row(format!("{row} | ")))?;
 
-        align = align.saturating_sub(team.len() + points.len() + 2);
-        write!(f, "{row_l}{team} {points}", row_l = style::row(format!("{row} | ")))?;
+        if let Some(team) = team
+        {
+            let team = style::team_line(format!("{team}:"));
+            let points = style::points_line(&**points);
+
+            align = align.saturating_sub(team.len() + points.len() + 2);
+            write!(f, "{team} {points}")?;
+        }
+
+        if let Some(comment) = comment
+        {
+            let space = if team.is_some() {" "} else {""};
+            let comment = style::comment_line(format!("{space}{COMMENT} {comment}"));
+
+            align = align.saturating_sub(comment.len());
+            write!(f, "{comment}")?;
+        }
 
         if let Some(effect) = effect.as_ref()
         {
@@ -33,4 +48,4 @@ impl Display for LineExample
 
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -6,6 +6,8 @@ moddef::moddef!(
     flat(pub) mod {
         arg_usage,
         call_example,
+        commands_usages,
+        exit_codes,
         line_example,
         flag_help,
         flag_usage,
@@ -21,7 +23,9 @@ const ALIGN: usize = 8*4;
 pub struct Help
 {
     exe: &'static str,
-    flags: Vec<FlagKind>
+    flags: Vec<FlagKind>,
+    /// Whether an invalid or missing argument was turned into help, instead of help just being asked for.
+    caught: bool
 }
 
 pub enum Catch
@@ -51,11 +55,23 @@ impl HelpCatchable for InvalidArg
             InvalidArg::NonexistentFlag { .. }
             | InvalidArg::IntegerAfterHelp => Err(self),
 
+            InvalidArg::ArgAfterCommand { .. } => Ok(Catch::Args),
+
             InvalidArg::UseTwoDots
             | InvalidArg::CannotParseInteger { .. }
             | InvalidArg::NotInOrder { .. }
             | InvalidArg::RoundZero => Ok(Catch::Args),
 
+            InvalidArg::NonexistentFormat { .. } => Ok(Catch::Flag(FlagKind::Output)),
+            InvalidArg::InvalidDelay { .. } => Ok(Catch::Flag(FlagKind::Delay)),
+            InvalidArg::NonexistentAggregate { .. } => Ok(Catch::Flag(FlagKind::Aggregate)),
+            InvalidArg::InvalidBest { .. } => Ok(Catch::Flag(FlagKind::Best)),
+            InvalidArg::NonexistentTieBreak { .. } => Ok(Catch::Flag(FlagKind::TieBreak)),
+            InvalidArg::NonexistentRanking { .. } => Ok(Catch::Flag(FlagKind::Ranking)),
+            InvalidArg::InvalidWeight { .. } => Ok(Catch::Flag(FlagKind::Weights)),
+            InvalidArg::InvalidCount { .. } => Ok(Catch::Flag(FlagKind::Count)),
+            InvalidArg::InvalidDecimals { .. } => Ok(Catch::Flag(FlagKind::Decimals)),
+
             InvalidArg::InvalidFlag { error } => Ok(Catch::Flag(error.related_flag()))
         }
     }
@@ -67,6 +83,16 @@ impl HelpCatchable for ExpectedArg
         match self
         {
             ExpectedArg::Filename => Ok(Catch::Flag(FlagKind::File)),
+            ExpectedArg::Format => Ok(Catch::Flag(FlagKind::Output)),
+            ExpectedArg::Delay => Ok(Catch::Flag(FlagKind::Delay)),
+            ExpectedArg::Aggregate => Ok(Catch::Flag(FlagKind::Aggregate)),
+            ExpectedArg::Best => Ok(Catch::Flag(FlagKind::Best)),
+            ExpectedArg::TieBreak => Ok(Catch::Flag(FlagKind::TieBreak)),
+            ExpectedArg::Ranking => Ok(Catch::Flag(FlagKind::Ranking)),
+            ExpectedArg::Weights => Ok(Catch::Flag(FlagKind::Weights)),
+            ExpectedArg::Count => Ok(Catch::Flag(FlagKind::Count)),
+            ExpectedArg::Decimals => Ok(Catch::Flag(FlagKind::Decimals)),
+            ExpectedArg::Since => Ok(Catch::Flag(FlagKind::Since)),
         }
     }
 }
@@ -77,7 +103,8 @@ impl Help
     {
         Self {
             exe,
-            flags: Vec::new()
+            flags: Vec::new(),
+            caught: false
         }
     }
 
@@ -100,6 +127,7 @@ impl Help
 
     pub fn reap_catch(&mut self, catch: Catch)
     {
+        self.caught = true;
         match catch
         {
             Catch::Args => (),
@@ -109,10 +137,12 @@ impl Help
 
     pub fn and(&mut self, help: Help)
     {
-        let Help {exe, flags} = help;
+        let Help {exe, flags, caught} = help;
 
         assert_eq!(self.exe, exe, "How did this happen?");
 
+        self.caught |= caught;
+
         self.add_flag(FlagKind::Help);
 
         for flag in flags
@@ -137,13 +167,18 @@ impl Help
             self.add_flag(flag);
         }
     }
+
+    pub fn caught(&self) -> bool
+    {
+        self.caught
+    }
 }
 
 impl Display for Help
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        let Self { exe, flags } = self;
+        let Self { exe, flags, caught: _ } = self;
 
         let mut flags = flags.iter();
         if let Some(&flag) = flags.next()
@@ -174,8 +209,12 @@ impl Display for Help
         let flags_usage = FlagsUsages {
             exe
         };
+        let commands_usage = CommandsUsages {
+            exe
+        };
         let rules = Rules;
-        write!(f, "{rules}\n\n{arg_usage}\n\n{flags_usage}")
+        let exit_codes = ExitCodes;
+        write!(f, "{rules}\n\n{arg_usage}\n\n{commands_usage}\n\n{flags_usage}\n\n{exit_codes}")
     }
 }
 
@@ -210,6 +249,7 @@ impl Help
             msg: (msg + ".").into_boxed_str(),
             error: None,
             line: None,
+            related: None,
             hint: None,
             docs: Some(Docs::Help(self))
         }
@@ -1,6 +1,6 @@
 use core::fmt::Display;
 
-use crate::style;
+use crate::{record::{Directive, ABSENT, COMMENT, DIRECTIVE, ESCAPE, JOKER, JOKER_FACTOR}, style};
 
 use super::LineExample;
 
@@ -12,10 +12,46 @@ impl Display for Rules
     {
         let examples = [
             LineExample {
-                team: "Quizzbuzz".into(),
+                team: Some(format!("{DIRECTIVE}{rounds}", rounds = Directive::Rounds).into_boxed_str()),
+                points: "Music, Geography, Picture round".into(),
+                comment: None,
+                effect: Some("Optionally gives each round a name.".into()),
+                row: 1
+            },
+            LineExample {
+                team: Some("Quizzbuzz".into()),
                 points: "3 5 15".into(),
+                comment: None,
                 effect: Some("A team that got 3 points in the first round, then 5, and then 15 in the final.".into()),
-                row: 1
+                row: 2
+            },
+            LineExample {
+                team: Some("Kate's Bush".into()),
+                points: format!("42.5 {ABSENT} 0").into_boxed_str(),
+                comment: None,
+                effect: Some(format!("A team that didn't participate in the second round, written as '{ABSENT}', and got 0 points in the final.").into_boxed_str()),
+                row: 3
+            },
+            LineExample {
+                team: Some("Blank".into()),
+                points: "7+2 10-1 1/2".into(),
+                comment: None,
+                effect: Some("Points can be calculated with '+', '-', '*', '/' and parentheses, as long as there are no spaces.".into()),
+                row: 4
+            },
+            LineExample {
+                team: None,
+                points: "".into(),
+                comment: Some("Late arrivals".into()),
+                effect: Some(format!("Everything after a '{COMMENT}' is a comment, and is ignored.").into_boxed_str()),
+                row: 5
+            },
+            LineExample {
+                team: Some(format!("Table {ESCAPE}{COMMENT}7").into_boxed_str()),
+                points: "0 12".into(),
+                comment: Some("Missed the first round".into()),
+                effect: Some(format!("Use '{ESCAPE}{COMMENT}' to put a '{COMMENT}' in a team name.").into_boxed_str()),
+                row: 6
             }
         ];
 
@@ -33,6 +69,19 @@ impl Display for Rules
             writeln!(f)?;
         }
 
-        write!(f, "{rules}", rules = style::info("One team on each line, followed by a ':', then the team's points for each round separated by whitespace."))
+        write!(f, "{rules}", rules = style::info(format!(
+            "One team on each line, followed by a ':', then the team's points for each round separated by whitespace.\n\
+            Write '{ABSENT}' for a round the team didn't participate in. Missing rounds at the end of a line count as not participated too.\n\
+            Points can be written as a sum like \"7+2\", using '+', '-', '*', '/' and parentheses without any spaces.\n\
+            Points followed by a '{JOKER}', like \"5{JOKER}\", are the team's joker round, and count {JOKER_FACTOR} times. Each team only has one joker.\n\
+            Blank lines are ignored, and so is everything after a '{COMMENT}', unless it's written as '{ESCAPE}{COMMENT}'.\n\
+            A line starting with \"{DIRECTIVE}{rounds}:\" gives names to the rounds, separated by ','. The names can be used instead of round numbers on the commandline.\n\
+            A round named like a command, like \"season\", is taken as the command when it comes first, so write it as \"season..season\" instead.\n\
+            A line starting with \"{DIRECTIVE}{tie_break}:\" lists the rules for breaking ties, separated by ','.\n\
+            A line starting with \"{DIRECTIVE}{weights}:\" gives each round a weight that its points are multiplied by, separated by ','.",
+            rounds = Directive::Rounds,
+            tie_break = Directive::TieBreak,
+            weights = Directive::Weights
+        )))
     }
 }
\ No newline at end of file
@@ -1,17 +1,23 @@
 use std::path::Path;
 
-use error::Error;
+use error::{Error, ExitCode};
 
 moddef::moddef!(
     flat mod {
         call,
+        command,
         run,
         error,
         flag,
         record,
         round,
         score,
-        help
+        scoring,
+        help,
+        output,
+        rational,
+        reveal,
+        watch
     },
     mod {
         style,
@@ -49,39 +55,117 @@ where
     Ok(collection)
 }
 
-// TODO: use .checked_signed_diff from std once stabilized
-fn checked_signed_diff(lhs: usize, rhs: usize) -> Option<isize>
-{
-    let res = lhs.wrapping_sub(rhs) as isize;
-    let overflow = (lhs >= rhs) == (res < 0);
-
-    if !overflow { Some(res) } else { None }
-}
-
 fn default_file_path() -> &'static Path
 {
     Path::new("./poengsum.txt")
 }
 
-fn main()
+fn main() -> std::process::ExitCode
 {
-    if let Err(error) = run(std::env::args())
+    match run(std::env::args())
     {
-        eprintln!("{error}")
+        Ok(()) => ExitCode::Success.into(),
+        Err(error) => {
+            let code = error.exit_code();
+            // Help that was asked for isn't an error
+            if code == ExitCode::Success
+            {
+                println!("{error}");
+            }
+            else
+            {
+                eprintln!("{error}");
+            }
+            code.into()
+        }
     }
 }
 
 fn run(args: impl Iterator<Item = String>) -> Result<(), Error>
 {
-    Call::from_args(args)?.collect()?.scores()?.present();
-
-    Ok(())
+    Call::from_args(args)?.collect()?.present()
 }
 
 #[cfg(test)]
 mod tests
 {
-    use crate::{error::Error, flag::Flag, help::ArgUsage};
+    use std::path::PathBuf;
+
+    use crate::{error::{Error, ExitCode}, flag::{Flag, FlagKind}, help::ArgUsage, run::Run};
+
+    /// A poengsum-file, or a folder of them, that is removed when dropped, so that a failing test doesn't leave it behind.
+    pub(crate) struct TempFile
+    {
+        path: PathBuf
+    }
+
+    impl TempFile
+    {
+        pub(crate) fn new(name: &str, contents: &str) -> Self
+        {
+            let file = Self {
+                path: std::env::temp_dir().join(format!("poengsum-{name}-{pid}.txt", pid = std::process::id()))
+            };
+            file.write(contents);
+            file
+        }
+
+        pub(crate) fn dir(name: &str) -> Self
+        {
+            let path = std::env::temp_dir().join(format!("poengsum-{name}-{pid}", pid = std::process::id()));
+            std::fs::create_dir_all(&path).unwrap();
+            Self {
+                path
+            }
+        }
+
+        pub(crate) fn path(&self) -> &str
+        {
+            self.path.to_str().unwrap()
+        }
+
+        pub(crate) fn write(&self, contents: &str)
+        {
+            std::fs::write(&self.path, contents).unwrap();
+        }
+
+        pub(crate) fn read(&self) -> String
+        {
+            std::fs::read_to_string(&self.path).unwrap()
+        }
+
+        /// Writes a file in the folder.
+        pub(crate) fn write_in(&self, name: &str, contents: &str)
+        {
+            std::fs::write(self.path.join(name), contents).unwrap();
+        }
+
+        /// Runs with the arguments, reading this file.
+        pub(crate) fn run(&self, args: &[&str]) -> Result<Run, Error>
+        {
+            run(&[args, &["--file", self.path()]].concat())
+        }
+
+        /// The leaderboard of this file, with the arguments.
+        pub(crate) fn scores(&self, args: &[&str]) -> Result<String, Error>
+        {
+            Ok(self.run(args)?.scores()?.to_string())
+        }
+    }
+
+    impl Drop for TempFile
+    {
+        fn drop(&mut self)
+        {
+            // Already gone if the test removed it
+            let _ = if self.path.is_dir() {std::fs::remove_dir_all(&self.path)} else {std::fs::remove_file(&self.path)};
+        }
+    }
+
+    pub(crate) fn run(args: &[&str]) -> Result<Run, Error>
+    {
+        crate::Call::from_args(core::iter::once("poengsum").chain(args.iter().copied()).map(String::from))?.collect()
+    }
 
     fn test(args: impl Iterator<Item = String>) -> Result<(), Error>
     {
@@ -119,7 +203,8 @@ mod tests
             test(example.into_args())?;
         }
 
-        for flag in Flag::VARIANTS
+        // These wait for the file to change or for someone to press Enter, or change the file, so they can't be tested like this
+        for flag in Flag::VARIANTS.into_iter().filter(|flag| !matches!(flag, FlagKind::Watch | FlagKind::Reveal | FlagKind::Delay | FlagKind::Podium | FlagKind::New))
         {
             for example in flag.examples("poengsum")
             {
@@ -129,4 +214,12 @@ mod tests
 
         Ok(())
     }
+
+    #[test]
+    fn no_executable()
+    {
+        let error = crate::run(core::iter::empty()).unwrap_err();
+        assert!(matches!(error, Error::NoExecutable));
+        assert_eq!(error.exit_code(), ExitCode::NoExecutable);
+    }
 }
@@ -0,0 +1,274 @@
+use core::fmt::Display;
+
+use unicode_width::UnicodeWidthChar;
+
+use crate::{rational::Rational, score::Scores, style, terminal};
+
+/// The widest a round can be in the chart.
+const MAX_STEP: usize = 12;
+/// The narrowest a round can be, so that a line still has room to turn between two rounds.
+const MIN_STEP: usize = 3;
+/// Room left for the team names after the chart, when it's shrunk to fit the terminal.
+const MIN_TEAM_WIDTH: usize = 8;
+
+/// The characters the lines are drawn with, named by the two sides a corner connects.
+struct Glyphs
+{
+    horizontal: char,
+    vertical: char,
+    left_down: char,
+    left_up: char,
+    up_right: char,
+    down_right: char,
+    cross: char,
+    marker: char
+}
+
+const UNICODE: Glyphs = Glyphs {
+    horizontal: '─',
+    vertical: '│',
+    left_down: '╮',
+    left_up: '╯',
+    up_right: '╰',
+    down_right: '╭',
+    cross: '┼',
+    marker: '●'
+};
+
+const ASCII: Glyphs = Glyphs {
+    horizontal: '-',
+    vertical: '|',
+    left_down: '+',
+    left_up: '+',
+    up_right: '+',
+    down_right: '+',
+    cross: '+',
+    marker: 'o'
+};
+
+/// The characters of the chart, and which team drew each of them.
+struct Canvas<'a>
+{
+    cells: Vec<Vec<Option<(char, usize)>>>,
+    glyphs: &'a Glyphs
+}
+
+impl Canvas<'_>
+{
+    /// Draws over whatever is there, except that a line crossing another line becomes a cross.
+    fn put(&mut self, x: usize, y: usize, c: char, uid: usize)
+    {
+        let Glyphs { horizontal, vertical, cross, .. } = *self.glyphs;
+        let cell = &mut self.cells[y][x];
+        let c = match *cell
+        {
+            Some((old, _)) if (c == horizontal || c == vertical) && (old == horizontal || old == vertical || old == cross) && old != c => cross,
+            _ => c
+        };
+        *cell = Some((c, uid));
+    }
+
+    /// Draws a line from one round to the next, turning halfway between them if the place changed.
+    fn connect(&mut self, (x0, y0): (usize, usize), (x1, y1): (usize, usize), uid: usize)
+    {
+        let glyphs = self.glyphs;
+        let turn = x0 + (x1 - x0)/2;
+        if y0 == y1
+        {
+            for x in x0 + 1..x1
+            {
+                self.put(x, y0, glyphs.horizontal, uid);
+            }
+            return
+        }
+
+        for x in x0 + 1..turn
+        {
+            self.put(x, y0, glyphs.horizontal, uid);
+        }
+        let (first, last, between) = if y1 > y0
+        {
+            (glyphs.left_down, glyphs.up_right, y0 + 1..y1)
+        }
+        else
+        {
+            (glyphs.left_up, glyphs.down_right, y1 + 1..y0)
+        };
+        self.put(turn, y0, first, uid);
+        for y in between
+        {
+            self.put(turn, y, glyphs.vertical, uid);
+        }
+        self.put(turn, y1, last, uid);
+        for x in turn + 1..x1
+        {
+            self.put(x, y1, glyphs.horizontal, uid);
+        }
+    }
+}
+
+/// A bump chart of how the place of each team changed from round to round.
+pub struct Chart<'a>
+{
+    scores: &'a Scores
+}
+
+impl<'a> Chart<'a>
+{
+    pub fn new(scores: &'a Scores) -> Self
+    {
+        Self {
+            scores
+        }
+    }
+
+    fn truncate(label: &str, width: usize) -> String
+    {
+        let mut taken = 0;
+        label.chars()
+            .take_while(|c| {
+                taken += c.width().unwrap_or(0);
+                taken <= width
+            }).collect()
+    }
+}
+
+impl Display for Chart<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let glyphs = if supports_unicode::supports_unicode() {&UNICODE} else {&ASCII};
+
+        // The leaders are drawn last, so that their lines are on top
+        let mut scores = self.scores.ordered();
+        scores.sort_by_key(|score| core::cmp::Reverse(score.place));
+
+        let rows = scores.iter()
+            .flat_map(|score| score.history.iter())
+            .map(|place| place.floor().max(1) as usize)
+            .max()
+            .unwrap_or(1);
+        let row = |place: &Rational| (place.floor().max(1) as usize - 1).min(rows - 1);
+
+        let labels = self.scores.columns()
+            .iter()
+            .map(|&round| self.scores.round_label(round).into_owned())
+            .collect::<Vec<_>>();
+        let place_width = format!("{rows}.").len();
+
+        let mut step = labels.iter()
+            .map(|label| label.chars().map(|c| c.width().unwrap_or(0)).sum::<usize>() + 1)
+            .max()
+            .unwrap_or(0)
+            .clamp(MIN_STEP, MAX_STEP);
+        // Shrink the rounds so that the chart doesn't wrap in the terminal
+        if let Some(max_width) = terminal::width() && labels.len() > 1
+        {
+            let room = max_width.saturating_sub(place_width + 2 + MIN_TEAM_WIDTH);
+            step = step.min(room/(labels.len() - 1)).max(MIN_STEP);
+        }
+        let width = labels.len().saturating_sub(1)*step + 1;
+
+        let mut canvas = Canvas {
+            cells: vec![vec![None; width]; rows],
+            glyphs
+        };
+        for score in scores.iter()
+        {
+            for (i, places) in score.history.windows(2).enumerate()
+            {
+                canvas.connect((i*step, row(&places[0])), ((i + 1)*step, row(&places[1])), score.uid);
+            }
+        }
+        for score in scores.iter()
+        {
+            for (i, place) in score.history.iter().enumerate()
+            {
+                canvas.put(i*step, row(place), glyphs.marker, score.uid);
+            }
+        }
+
+        scores.reverse();
+        for (y, cells) in canvas.cells.iter().enumerate()
+        {
+            let place = format!("{place}.", place = y + 1);
+            write!(f, "{padding}{place} ", padding = " ".repeat(place_width - place.len()), place = style::place(Rational::integer(y as i128 + 1)))?;
+
+            let teams = scores.iter()
+                .filter(|score| row(&score.place) == y)
+                .map(|score| style::chart_team(&*score.team, score.uid).to_string())
+                .collect::<Vec<_>>();
+            let end = if teams.is_empty()
+            {
+                cells.iter().rposition(Option::is_some).map_or(0, |end| end + 1)
+            }
+            else
+            {
+                width
+            };
+            for cell in &cells[..end]
+            {
+                match *cell
+                {
+                    Some((c, uid)) => write!(f, "{c}", c = style::chart_line(c, uid))?,
+                    None => write!(f, " ")?
+                }
+            }
+            if !teams.is_empty()
+            {
+                write!(f, " {teams}", teams = teams.join(", "))?;
+            }
+            writeln!(f)?;
+        }
+
+        write!(f, "{padding}", padding = " ".repeat(place_width + 1))?;
+        let last = labels.len().saturating_sub(1);
+        for (i, label) in labels.iter().enumerate()
+        {
+            let label = Self::truncate(label, step - 1);
+            if i == last
+            {
+                write!(f, "{label}", label = style::line(label))?;
+            }
+            else
+            {
+                let padding = " ".repeat(step - label.chars().map(|c| c.width().unwrap_or(0)).sum::<usize>());
+                write!(f, "{label}{padding}", label = style::line(label))?;
+            }
+        }
+        writeln!(f)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, score::Scores, tests::TempFile};
+
+    #[test]
+    fn chart() -> Result<(), Error>
+    {
+        let file = TempFile::new("chart", "@rounds: Music, Halftime, Geography, Final\nQuizzbuzz: 1 1 10 10\nKate's Bush: 5 5 1 1\nBlank: 3 3 3 3\n");
+        let history = |scores: &Scores, team: &str| scores.ordered()
+            .into_iter()
+            .find(|score| &*score.team == team)
+            .map(|score| score.history.iter().map(|place| place.floor()).collect::<Vec<_>>())
+            .unwrap();
+
+        let all = file.run(&["--chart"])?.scores()?;
+        assert_eq!(history(&all, "Quizzbuzz"), [3, 3, 1, 1]);
+        assert_eq!(history(&all, "Kate's Bush"), [1, 1, 2, 2]);
+        assert_eq!(history(&all, "Blank"), [2, 2, 3, 2]);
+
+        // One line for each place, and one for the names of the rounds
+        let chart = all.to_string();
+        assert_eq!(chart.lines().count(), 4);
+        assert!(chart.lines().last().unwrap().contains("Halftime"));
+
+        let some = file.run(&["Final", "Music", "--chart"])?.scores()?;
+        assert_eq!(history(&some, "Kate's Bush"), [3, 2]);
+        assert_eq!(history(&some, "Blank"), [2, 2]);
+
+        Ok(())
+    }
+}
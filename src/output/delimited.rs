@@ -0,0 +1,158 @@
+use core::fmt::{Display, Write};
+
+use crate::{rational::Rational, score::Scores};
+
+struct Field<'a>
+{
+    text: &'a str,
+    delimiter: char
+}
+
+impl Display for Field<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let Self { text, delimiter } = *self;
+
+        let needs_quotes = text.chars().any(|c| c == delimiter || c == '"' || c == '\n' || c == '\r')
+            || text.trim() != text;
+        if !needs_quotes
+        {
+            return f.write_str(text)
+        }
+
+        f.write_char('"')?;
+        for c in text.chars()
+        {
+            if c == '"'
+            {
+                f.write_char('"')?;
+            }
+            f.write_char(c)?;
+        }
+        f.write_char('"')
+    }
+}
+
+/// Comma- or tab-separated values, for pasting into a spreadsheet.
+pub struct Delimited<'a>
+{
+    scores: &'a Scores,
+    delimiter: char,
+    long: bool
+}
+
+impl<'a> Delimited<'a>
+{
+    pub fn new(scores: &'a Scores, delimiter: char, long: bool) -> Self
+    {
+        Self {
+            scores,
+            delimiter,
+            long
+        }
+    }
+
+    fn write_row<'b>(&self, f: &mut std::fmt::Formatter<'_>, row: impl IntoIterator<Item = &'b str>) -> std::fmt::Result
+    {
+        let mut first = true;
+        for text in row
+        {
+            if !first
+            {
+                f.write_char(self.delimiter)?;
+            }
+            first = false;
+            write!(f, "{field}", field = Field {
+                text,
+                delimiter: self.delimiter
+            })?;
+        }
+        // RFC 4180 wants CRLF, which all spreadsheet applications accept
+        f.write_str("\r\n")
+    }
+}
+
+impl Display for Delimited<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let columns = self.scores.columns()
+            .iter()
+            .map(|&round| self.scores.round_label(round).into_owned())
+            .collect::<Vec<_>>();
+
+        let notation = self.scores.notation();
+        let points = |points: Option<Rational>| points.map(|points| notation.format(points))
+            .unwrap_or_default();
+
+        if self.long
+        {
+            self.write_row(f, ["place", "team", "total", "climb", "round", "points"])?;
+
+            for score in self.scores.ordered()
+            {
+                let place = score.place.to_string();
+                let total = points(Some(score.points));
+                let climb = score.climb.to_string();
+
+                for (round, &effective) in columns.iter().zip(score.effective.iter())
+                {
+                    let round_points = points(effective);
+                    self.write_row(f, [&*place, &score.team, &total, &climb, round, &round_points])?;
+                }
+            }
+        }
+        else
+        {
+            self.write_row(f, ["place", "team"].into_iter()
+                .chain(columns.iter().map(String::as_str))
+                .chain(["total", "climb"])
+            )?;
+
+            for score in self.scores.ordered()
+            {
+                let place = score.place.to_string();
+                let rounds = score.effective.iter()
+                    .map(|&effective| points(effective))
+                    .collect::<Vec<_>>();
+                let total = points(Some(score.points));
+                let climb = score.climb.to_string();
+
+                self.write_row(f, [&*place, &score.team].into_iter()
+                    .chain(rounds.iter().map(String::as_str))
+                    .chain([&*total, &climb])
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn csv_is_quoted() -> Result<(), Error>
+    {
+        let file = TempFile::new("csv", "Kate's Bush, \"the band\": 42.5 21\nQuizzbuzz: 3 5 15\n");
+
+        assert_eq!(
+            file.scores(&["--output", "csv"])?,
+            "place,team,1,2,3,total,climb\r\n\
+            1,\"Kate's Bush, \"\"the band\"\"\",42.5,21,,63.5,0\r\n\
+            2,Quizzbuzz,3,5,15,23,0\r\n"
+        );
+        assert_eq!(
+            file.scores(&["--output", "tsv", "--long", "2"])?,
+            "place\tteam\ttotal\tclimb\tround\tpoints\r\n\
+            1\t\"Kate's Bush, \"\"the band\"\"\"\t21\t0\t2\t21\r\n\
+            2\tQuizzbuzz\t5\t0\t2\t5\r\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,45 @@
+use core::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format
+{
+    Text,
+    Json,
+    Csv,
+    Tsv,
+    Markdown,
+    Html
+}
+
+impl Format
+{
+    pub const VARIANT_COUNT: usize = 6; //core::mem::variant_count::<Format>(); TODO use this instead when stable
+    pub const VARIANTS: [Format; Self::VARIANT_COUNT] = [Format::Text, Format::Json, Format::Csv, Format::Tsv, Format::Markdown, Format::Html];
+
+    pub const fn name(self) -> &'static str
+    {
+        match self
+        {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Csv => "csv",
+            Self::Tsv => "tsv",
+            Self::Markdown => "markdown",
+            Self::Html => "html"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        Self::VARIANTS.into_iter()
+            .find(|format| format.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+impl Display for Format
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.name().fmt(f)
+    }
+}
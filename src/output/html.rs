@@ -0,0 +1,140 @@
+use core::{cmp::Ordering, fmt::{Display, Write}};
+
+use crate::{rational::Rational, score::Scores, style};
+
+const STYLESHEET: &str = "\
+body { font-family: sans-serif; margin: 2em; background: #fff; color: #222; }
+table { border-collapse: collapse; }
+th, td { padding: 0.25em 0.75em; }
+th { border-bottom: 2px solid #222; }
+tr:nth-child(even) td { background: #f2f2f2; }
+.place, .points, .total { text-align: right; font-variant-numeric: tabular-nums; }
+.team { text-align: left; }
+.total { font-weight: bold; }
+.missing { color: #999; }
+.discarded { color: #999; text-decoration: line-through; }
+.up { color: #080; }
+.down { color: #c00; }";
+
+struct HtmlStr<'a>(&'a str);
+
+impl Display for HtmlStr<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        for c in self.0.chars()
+        {
+            match c
+            {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&#39;")?,
+                c => f.write_char(c)?
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A standalone HTML page with inline CSS, so it can be opened offline.
+pub struct Html<'a>
+{
+    scores: &'a Scores,
+    table: bool
+}
+
+impl<'a> Html<'a>
+{
+    pub fn new(scores: &'a Scores, table: bool) -> Self
+    {
+        Self {
+            scores,
+            table
+        }
+    }
+}
+
+impl Display for Html<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let columns = if self.table
+        {
+            self.scores.columns()
+        }
+        else
+        {
+            &[]
+        };
+
+        writeln!(f, "<!DOCTYPE html>")?;
+        writeln!(f, "<html>")?;
+        writeln!(f, "<head>")?;
+        writeln!(f, "<meta charset=\"utf-8\">")?;
+        writeln!(f, "<title>Leaderboard</title>")?;
+        writeln!(f, "<style>\n{STYLESHEET}\n</style>")?;
+        writeln!(f, "</head>")?;
+        writeln!(f, "<body>")?;
+        writeln!(f, "<table>")?;
+
+        write!(f, "<thead><tr><th class=\"place\">#</th><th class=\"team\">Team</th>")?;
+        for round in columns
+        {
+            write!(f, "<th class=\"points\">{round}</th>", round = HtmlStr(&self.scores.round_label(*round)))?;
+        }
+        writeln!(f, "<th class=\"total\">Total</th><th></th></tr></thead>")?;
+
+        writeln!(f, "<tbody>")?;
+        let notation = self.scores.notation();
+        for score in self.scores.ordered()
+        {
+            write!(f, "<tr><td class=\"place\">{place}.</td><td class=\"team\">{team}</td>", place = score.place, team = HtmlStr(&score.team))?;
+            for (i, ((entry, &weight), &counted)) in score.rounds.iter().zip(self.scores.weights().iter()).zip(score.counted.iter()).take(columns.len()).enumerate()
+            {
+                let class = if counted {"points"} else {"points discarded"};
+                match (score.weighted(i, weight, notation), entry.expression())
+                {
+                    (Some(points), Some(expression)) => write!(f, "<td class=\"{class}\" title=\"{expression}\">{points}</td>", expression = HtmlStr(expression), points = HtmlStr(&points))?,
+                    (Some(points), None) => write!(f, "<td class=\"{class}\">{points}</td>", points = HtmlStr(&points))?,
+                    (None, _) => write!(f, "<td class=\"points missing\">·</td>")?
+                }
+            }
+            write!(f, "<td class=\"total\">{points}</td>", points = notation.format(score.points))?;
+
+            let climb = style::climb_text(score.climb);
+            match score.climb.cmp(&Rational::ZERO)
+            {
+                Ordering::Greater => write!(f, "<td class=\"climb up\">{climb}</td>")?,
+                Ordering::Less => write!(f, "<td class=\"climb down\">{climb}</td>")?,
+                Ordering::Equal => write!(f, "<td class=\"climb\"></td>")?
+            }
+            writeln!(f, "</tr>")?;
+        }
+        writeln!(f, "</tbody>")?;
+
+        writeln!(f, "</table>")?;
+        writeln!(f, "</body>")?;
+        writeln!(f, "</html>")
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn html_is_escaped() -> Result<(), Error>
+    {
+        let file = TempFile::new("html", "<b>Bold</b> & *stars* | co.: 3 5\nQuizzbuzz: 1 9\n");
+
+        let html = file.scores(&["--output", "html", "--table"])?;
+        assert!(html.contains("<td class=\"team\">&lt;b&gt;Bold&lt;/b&gt; &amp; *stars* | co.</td>"));
+        assert!(html.contains("<td class=\"climb up\">↑1</td>"));
+        assert!(!html.contains('\x1b'));
+
+        Ok(())
+    }
+}
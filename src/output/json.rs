@@ -0,0 +1,180 @@
+use core::fmt::{Display, Write};
+
+use crate::{rational::Rational, score::Scores};
+
+/// Bumped whenever a field is renamed, removed or changes meaning.
+pub const JSON_SCHEMA_VERSION: usize = 6;
+const JSON_SCHEMA: &str = "poengsum-leaderboard";
+
+struct JsonStr<'a>(&'a str);
+
+impl Display for JsonStr<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        f.write_char('"')?;
+        for c in self.0.chars()
+        {
+            match c
+            {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                '\r' => f.write_str("\\r")?,
+                '\t' => f.write_str("\\t")?,
+                c if c.is_control() => write!(f, "\\u{code:04x}", code = c as u32)?,
+                c => f.write_char(c)?
+            }
+        }
+        f.write_char('"')
+    }
+}
+
+/// A number if it can be written exactly as a decimal number, and a string like "181/3" if not, so that nothing is lost.
+struct JsonNumber(Option<Rational>);
+
+impl Display for JsonNumber
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self.0
+        {
+            Some(number) if number.is_decimal() => write!(f, "{number}"),
+            Some(number) => write!(f, "\"{numer}/{denom}\"", numer = number.numer(), denom = number.denom()),
+            None => f.write_str("null")
+        }
+    }
+}
+
+pub struct Json<'a>
+{
+    scores: &'a Scores
+}
+
+impl<'a> Json<'a>
+{
+    pub fn new(scores: &'a Scores) -> Self
+    {
+        Self {
+            scores
+        }
+    }
+}
+
+impl Display for Json<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        writeln!(f, "{{")?;
+        writeln!(f, "  \"schema\": {schema},", schema = JsonStr(JSON_SCHEMA))?;
+        writeln!(f, "  \"version\": {JSON_SCHEMA_VERSION},")?;
+
+        let rounds = self.scores.columns()
+            .iter()
+            .map(|round| (round + 1).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(f, "  \"rounds\": [{rounds}],")?;
+
+        let round_names = self.scores.columns()
+            .iter()
+            .map(|&round| match self.scores.round_names().name(round)
+            {
+                Some(name) => JsonStr(name).to_string(),
+                None => "null".into()
+            }).collect::<Vec<_>>()
+            .join(", ");
+        writeln!(f, "  \"round_names\": [{round_names}],")?;
+
+        let weights = self.scores.weights()
+            .iter()
+            .map(|&weight| JsonNumber(Some(weight)).to_string())
+            .collect::<Vec<_>>()
+            .join(", ");
+        writeln!(f, "  \"weights\": [{weights}],")?;
+
+        write!(f, "  \"scores\": [")?;
+        let mut first = true;
+        for score in self.scores.ordered()
+        {
+            if !first
+            {
+                write!(f, ",")?;
+            }
+            first = false;
+
+            let rounds = score.rounds.iter()
+                .map(|entry| JsonNumber(entry.points()).to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let effective = score.effective.iter()
+                .map(|&points| JsonNumber(points).to_string())
+                .collect::<Vec<_>>()
+                .join(", ");
+            let expressions = score.rounds.iter()
+                .map(|entry| match entry.expression()
+                {
+                    Some(expression) => JsonStr(expression).to_string(),
+                    None => "null".into()
+                }).collect::<Vec<_>>()
+                .join(", ");
+
+            write!(f, "\n    {{")?;
+            write!(f, "\"team\": {team}, ", team = JsonStr(&score.team))?;
+            write!(f, "\"uid\": {uid}, ", uid = score.uid)?;
+            write!(f, "\"place\": {place}, ", place = JsonNumber(Some(score.place)))?;
+            write!(f, "\"points\": {points}, ", points = JsonNumber(Some(score.points)))?;
+            write!(f, "\"climb\": {climb}, ", climb = JsonNumber(Some(score.climb)))?;
+            write!(f, "\"rounds\": [{rounds}], ")?;
+            write!(f, "\"expressions\": [{expressions}], ")?;
+            write!(f, "\"played\": {played}, ", played = score.played())?;
+            match score.tie_break
+            {
+                Some(tie_break) => write!(f, "\"tie_break\": {tie_break}, ", tie_break = JsonStr(tie_break.name()))?,
+                None => write!(f, "\"tie_break\": null, ")?
+            }
+            write!(f, "\"effective\": [{effective}], ")?;
+            write!(f, "\"counted\": [{counted}]", counted = score.counted.iter().map(bool::to_string).collect::<Vec<_>>().join(", "))?;
+            write!(f, "}}")?;
+        }
+        if !first
+        {
+            writeln!(f)?;
+            write!(f, "  ")?;
+        }
+        writeln!(f, "]")?;
+
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn json_is_unstyled() -> Result<(), Error>
+    {
+        let json = crate::tests::run(&["--output", "json", "--table"])?
+            .scores()?
+            .to_string();
+
+        assert!(!json.contains('\x1b'));
+        assert!(json.contains("\"version\": 6"));
+        assert!(json.contains("{\"team\": \"The Good Seeds\", \"uid\": 10, \"place\": 1, \"points\": 143, \"climb\": 0, \"rounds\": [49, 47, 47], \"expressions\": [null, null, null], \"played\": 3, \"tie_break\": null, \"effective\": [49, 47, 47], \"counted\": [true, true, true]}"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fractions_are_exact() -> Result<(), Error>
+    {
+        let file = TempFile::new("json-fractions", "Quizzbuzz: 10/3 0.5\nBlank: 1 2\n");
+        let json = file.scores(&["--output", "json"])?;
+
+        assert!(json.contains("\"points\": \"23/6\", \"climb\": 0, \"rounds\": [\"10/3\", 0.5]"));
+
+        Ok(())
+    }
+}
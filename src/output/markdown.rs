@@ -0,0 +1,103 @@
+use core::fmt::{Display, Write};
+
+use crate::{score::Scores, style};
+
+struct MarkdownStr<'a>(&'a str);
+
+impl Display for MarkdownStr<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        for c in self.0.chars()
+        {
+            if matches!(c, '\\' | '|' | '*' | '_' | '`' | '[' | ']' | '<' | '>' | '#')
+            {
+                f.write_char('\\')?;
+            }
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct Markdown<'a>
+{
+    scores: &'a Scores,
+    table: bool
+}
+
+impl<'a> Markdown<'a>
+{
+    pub fn new(scores: &'a Scores, table: bool) -> Self
+    {
+        Self {
+            scores,
+            table
+        }
+    }
+}
+
+impl Display for Markdown<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let columns = if self.table
+        {
+            self.scores.columns()
+        }
+        else
+        {
+            &[]
+        };
+
+        write!(f, "| # | Team |")?;
+        for round in columns
+        {
+            write!(f, " {round} |", round = MarkdownStr(&self.scores.round_label(*round)))?;
+        }
+        writeln!(f, " Total | |")?;
+
+        write!(f, "|--:|:---|")?;
+        for _ in columns
+        {
+            write!(f, "--:|")?;
+        }
+        writeln!(f, "--:|:-|")?;
+
+        let notation = self.scores.notation();
+        for score in self.scores.ordered()
+        {
+            write!(f, "| {place}. | {team} |", place = score.place, team = MarkdownStr(&score.team))?;
+            for (i, (&weight, &counted)) in self.scores.weights().iter().zip(score.counted.iter()).take(columns.len()).enumerate()
+            {
+                match score.weighted(i, weight, notation)
+                {
+                    Some(points) if counted => write!(f, " {points} |", points = MarkdownStr(&points))?,
+                    Some(points) => write!(f, " ~~{points}~~ |", points = MarkdownStr(&points))?,
+                    None => write!(f, " · |")?
+                }
+            }
+            writeln!(f, " {points} | {climb} |", points = notation.format(score.points), climb = style::climb_text(score.climb))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn markdown_is_escaped() -> Result<(), Error>
+    {
+        let file = TempFile::new("markdown", "<b>Bold</b> & *stars* | co.: 3 5\nQuizzbuzz: 1 9\n");
+
+        let markdown = file.scores(&["--output", "markdown", "--table"])?;
+        assert!(markdown.contains("| 1. | Quizzbuzz | 1 | 9 | 10 | ↑1 |"));
+        assert!(markdown.contains("| 2. | \\<b\\>Bold\\</b\\> & \\*stars\\* \\| co. | 3 | 5 | 8 | ↓1 |"));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,13 @@
+moddef::moddef!(
+    flat(pub) mod {
+        chart,
+        delimited,
+        format,
+        html,
+        json,
+        markdown,
+        notation,
+        output,
+        table
+    }
+);
@@ -0,0 +1,29 @@
+use crate::rational::Rational;
+
+/// How points are written in the leaderboard.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Notation
+{
+    /// Exactly if they can be, like 60.3, and rounded to a couple of decimal places if not, like 60.33.
+    Decimal,
+    /// Always rounded to this many decimal places.
+    Decimals(usize),
+    /// As a whole number and a fraction, like 60 1/3.
+    Fractions
+}
+
+impl Notation
+{
+    /// The most decimal places that can be asked for.
+    pub const MAX_DECIMALS: usize = 12;
+
+    pub fn format(self, points: Rational) -> String
+    {
+        match self
+        {
+            Self::Decimal => format!("{points}"),
+            Self::Decimals(decimals) => format!("{points:.decimals$}"),
+            Self::Fractions => format!("{points}", points = points.fraction())
+        }
+    }
+}
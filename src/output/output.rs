@@ -0,0 +1,27 @@
+use super::{Format, Notation};
+
+#[derive(Clone, Copy)]
+pub struct Output
+{
+    pub format: Format,
+    pub rev: bool,
+    pub table: bool,
+    pub long: bool,
+    pub chart: bool,
+    pub notation: Notation
+}
+
+impl Output
+{
+    pub fn new() -> Self
+    {
+        Self {
+            format: Format::Text,
+            rev: false,
+            table: false,
+            long: false,
+            chart: false,
+            notation: Notation::Decimal
+        }
+    }
+}
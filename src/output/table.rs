@@ -0,0 +1,248 @@
+use core::fmt::Display;
+use std::borrow::Cow;
+
+use ansiterm::ANSIString;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+use crate::{score::Scores, style, terminal};
+
+const SEPARATOR: &str = "  ";
+const MIN_TEAM_WIDTH: usize = 8;
+
+#[derive(Clone, Copy)]
+enum Align
+{
+    Left,
+    Right
+}
+
+struct Cell<'a>
+{
+    text: ANSIString<'a>,
+    align: Align
+}
+
+impl Cell<'_>
+{
+    fn width(&self) -> usize
+    {
+        self.text.width()
+    }
+
+    fn write(&self, f: &mut std::fmt::Formatter<'_>, width: usize) -> std::fmt::Result
+    {
+        let padding = " ".repeat(width.saturating_sub(self.width()));
+        match self.align
+        {
+            Align::Left => write!(f, "{text}{padding}", text = self.text),
+            Align::Right => write!(f, "{padding}{text}", text = self.text)
+        }
+    }
+}
+
+pub struct Table<'a>
+{
+    scores: &'a Scores
+}
+
+impl<'a> Table<'a>
+{
+    pub fn new(scores: &'a Scores) -> Self
+    {
+        Self {
+            scores
+        }
+    }
+
+    fn truncate(team: &str, width: usize) -> Cow<'_, str>
+    {
+        if team.width() <= width
+        {
+            return team.into()
+        }
+
+        let ellipsis = if supports_unicode::supports_unicode() {'…'} else {'~'};
+        let mut taken = 0;
+        let mut end = 0;
+        for (i, c) in team.char_indices()
+        {
+            taken += c.width().unwrap_or(0);
+            if taken + ellipsis.width().unwrap_or(1) > width
+            {
+                break
+            }
+            end = i + c.len_utf8();
+        }
+
+        format!("{team}{ellipsis}", team = &team[..end]).into()
+    }
+}
+
+impl Display for Table<'_>
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let scores = self.scores.ordered();
+        let columns = self.scores.columns();
+        let notation = self.scores.notation();
+
+        let header = |text: String, align| Cell {
+            text: style::header(text),
+            align
+        };
+        let header_row = core::iter::once(header("#".into(), Align::Right))
+            .chain(core::iter::once(header("Team".into(), Align::Left)))
+            .chain(columns.iter()
+                .map(|&round| header(self.scores.round_label(round).into_owned(), Align::Right))
+            ).chain(core::iter::once(header("Total".into(), Align::Right)))
+            .collect::<Vec<_>>();
+
+        let rows = scores.iter()
+            .map(|score| {
+                let row = core::iter::once(Cell {
+                        text: style::place(score.place),
+                        align: Align::Right
+                    }).chain(core::iter::once(Cell {
+                        text: style::team(&*score.team),
+                        align: Align::Left
+                    })).chain(score.rounds.iter()
+                        .zip(self.scores.weights().iter())
+                        .zip(score.counted.iter())
+                        .enumerate()
+                        .map(|(i, ((entry, &weight), &counted))| Cell {
+                            // The points before the weight and joker are shown too, like in an expression
+                            text: match (score.weighted(i, weight, notation), entry.expression())
+                            {
+                                (Some(text), expression) => {
+                                    let text = match expression
+                                    {
+                                        Some(expression) => format!("{expression}={text}"),
+                                        None => text
+                                    };
+                                    if counted
+                                    {
+                                        style::points(text)
+                                    }
+                                    else
+                                    {
+                                        style::discarded(text)
+                                    }
+                                },
+                                (None, _) => style::missing()
+                            },
+                            align: Align::Right
+                        })
+                    ).chain(core::iter::once(Cell {
+                        text: style::points(notation.format(score.points)),
+                        align: Align::Right
+                    })).collect::<Vec<_>>();
+                (row, [style::climb(score.climb), style::tie_break(score.tie_break)])
+            }).collect::<Vec<_>>();
+
+        let mut widths = header_row.iter()
+            .map(Cell::width)
+            .collect::<Vec<_>>();
+        for (row, _) in rows.iter()
+        {
+            for (width, cell) in widths.iter_mut().zip(row.iter())
+            {
+                *width = (*width).max(cell.width());
+            }
+        }
+        let after_width = rows.iter()
+            .map(|(_, after)| after.iter().map(|text| text.width()).sum::<usize>())
+            .max()
+            .unwrap_or(0);
+
+        // Shrink the team column so that the rows don't wrap in the terminal
+        const TEAM: usize = 1;
+        let team_width = widths[TEAM];
+        if let Some(max_width) = terminal::width()
+        {
+            let fixed_width = widths.iter()
+                .enumerate()
+                .filter(|&(i, _)| i != TEAM)
+                .map(|(_, width)| width)
+                .sum::<usize>() + SEPARATOR.len()*(widths.len() - 1) + after_width;
+            widths[TEAM] = team_width.min(max_width.saturating_sub(fixed_width).max(MIN_TEAM_WIDTH));
+        }
+
+        let write_row = |f: &mut std::fmt::Formatter<'_>, row: &[Cell<'_>]| {
+            let mut first = true;
+            for (cell, &width) in row.iter().zip(widths.iter())
+            {
+                if !first
+                {
+                    write!(f, "{SEPARATOR}")?;
+                }
+                first = false;
+                cell.write(f, width)?;
+            }
+            Ok(())
+        };
+
+        write_row(f, &header_row)?;
+        writeln!(f)?;
+
+        for (mut row, [climb, tie_break]) in rows
+        {
+            if row[TEAM].width() > widths[TEAM]
+            {
+                let team = Self::truncate(&row[TEAM].text, widths[TEAM]).into_owned();
+                row[TEAM].text = style::team(team);
+            }
+            write_row(f, &row)?;
+            writeln!(f, "{climb}{tie_break}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use unicode_width::UnicodeWidthStr;
+
+    use crate::{error::Error, tests::TempFile};
+
+    /// The table without the terminal colors.
+    fn unstyled(table: &str) -> String
+    {
+        let mut text = String::new();
+        let mut escaped = false;
+        for c in table.chars()
+        {
+            match c
+            {
+                '\x1b' => escaped = true,
+                'm' if escaped => escaped = false,
+                c if !escaped => text.push(c),
+                _ => ()
+            }
+        }
+        text
+    }
+
+    #[test]
+    fn wide_characters_line_up() -> Result<(), Error>
+    {
+        let teams = ["Quizzbuzz", "東京クイズ", "🦀 Krabber", "Blank"];
+        let file = TempFile::new("table", "東京クイズ: 9 1\n🦀 Krabber: 7 2\nQuizzbuzz: 10 1\nBlank: 2 2\n");
+
+        let table = unstyled(&file.scores(&["--table"])?);
+        let lines = table.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), teams.len() + 1);
+
+        // Every team starts in the same column, and every row ends in the same column, however wide the characters are, or if the team is shortened to fit the terminal
+        let start = |line: &str, c: char| line[..line.find(c).unwrap()].width();
+        let team_start = start(lines[0], 'T');
+        for (line, team) in lines[1..].iter().zip(teams)
+        {
+            assert_eq!(start(line, team.chars().next().unwrap()), team_start, "{table}");
+            assert_eq!(line.width(), lines[0].width(), "{table}");
+        }
+
+        Ok(())
+    }
+}
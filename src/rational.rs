@@ -0,0 +1,434 @@
+use core::{cmp::Ordering, fmt::Display, str::FromStr};
+
+use crate::error::InvalidNumber;
+
+/// The most decimal places a number can be written with and still be exact.
+const MAX_EXACT_DECIMALS: u32 = 18;
+/// The largest power of ten in a number like "1e30".
+const MAX_EXPONENT: u32 = 30;
+
+/// An exact fraction, so that points like thirds and tenths add up without rounding errors.
+///
+/// Always kept in lowest terms, with a positive denominator, and a numerator that isn't `i128::MIN`, so that every number can be made positive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rational
+{
+    numer: i128,
+    denom: i128
+}
+
+impl Rational
+{
+    pub const ZERO: Self = Self::integer(0);
+    pub const ONE: Self = Self::integer(1);
+    /// The decimal places shown for numbers that can't be written exactly as a decimal number, like 1/3.
+    pub const DECIMALS: usize = 2;
+
+    /// # Panics
+    ///
+    /// If the number is `i128::MIN`.
+    pub const fn integer(n: i128) -> Self
+    {
+        assert!(n != i128::MIN, "integer is too large to be made positive");
+        Self {
+            numer: n,
+            denom: 1
+        }
+    }
+
+    /// Gives an error if the fraction is too large, even in lowest terms.
+    ///
+    /// # Panics
+    ///
+    /// If the denominator is zero.
+    pub fn new(numer: i128, denom: i128) -> Result<Self, InvalidNumber>
+    {
+        assert!(denom != 0, "denominator is zero");
+        // Without the signs, since -i128::MIN doesn't fit in an i128
+        let gcd = gcd(numer.unsigned_abs(), denom.unsigned_abs());
+        let magnitude = i128::try_from(numer.unsigned_abs()/gcd)
+            .map_err(|_| InvalidNumber::TooLarge)?;
+        let negative = (numer < 0) != (denom < 0);
+        Ok(Self {
+            numer: if negative {-magnitude} else {magnitude},
+            denom: i128::try_from(denom.unsigned_abs()/gcd).map_err(|_| InvalidNumber::TooLarge)?
+        })
+    }
+
+    pub fn numer(self) -> i128
+    {
+        self.numer
+    }
+
+    pub fn denom(self) -> i128
+    {
+        self.denom
+    }
+
+    pub fn is_zero(self) -> bool
+    {
+        self.numer == 0
+    }
+
+    /// Never too large, since the numerator is never `i128::MIN`.
+    pub fn abs(self) -> Self
+    {
+        Self {
+            numer: self.numer.abs(),
+            denom: self.denom
+        }
+    }
+
+    /// Gives nothing if the number is too large to be made positive.
+    pub fn checked_abs(self) -> Option<Self>
+    {
+        Some(Self {
+            numer: self.numer.checked_abs()?,
+            denom: self.denom
+        })
+    }
+
+    /// The largest whole number that isn't larger than this one.
+    pub fn floor(self) -> i128
+    {
+        self.numer.div_euclid(self.denom)
+    }
+
+    pub fn to_f64(self) -> f64
+    {
+        self.numer as f64/self.denom as f64
+    }
+
+    pub fn checked_add(self, rhs: Self) -> Option<Self>
+    {
+        // Never larger than the denominators, so it fits in an i128
+        let gcd = gcd(self.denom as u128, rhs.denom as u128) as i128;
+        let numer = self.numer.checked_mul(rhs.denom/gcd)?
+            .checked_add(rhs.numer.checked_mul(self.denom/gcd)?)?;
+        let denom = (self.denom/gcd).checked_mul(rhs.denom)?;
+        Self::new(numer, denom).ok()
+    }
+
+    pub fn checked_sub(self, rhs: Self) -> Option<Self>
+    {
+        self.checked_add(rhs.checked_neg()?)
+    }
+
+    pub fn checked_mul(self, rhs: Self) -> Option<Self>
+    {
+        // Cancelling first keeps the numbers small, and neither is larger than a denominator, so they fit in an i128
+        let a = gcd(self.numer.unsigned_abs(), rhs.denom as u128) as i128;
+        let b = gcd(rhs.numer.unsigned_abs(), self.denom as u128) as i128;
+        Self::new(
+            (self.numer/a).checked_mul(rhs.numer/b)?,
+            (self.denom/b).checked_mul(rhs.denom/a)?
+        ).ok()
+    }
+
+    /// Gives nothing if the divisor is zero, or the result is too large.
+    pub fn checked_div(self, rhs: Self) -> Option<Self>
+    {
+        if rhs.is_zero()
+        {
+            return None
+        }
+        self.checked_mul(Self::new(rhs.denom, rhs.numer).ok()?)
+    }
+
+    pub fn checked_neg(self) -> Option<Self>
+    {
+        Some(Self {
+            numer: self.numer.checked_neg()?,
+            denom: self.denom
+        })
+    }
+
+    /// How many decimal places it takes to write the number exactly, if it can be.
+    fn exact_decimals(self) -> Option<u32>
+    {
+        let mut denom = self.denom;
+        let (mut twos, mut fives) = (0, 0);
+        while denom % 2 == 0
+        {
+            denom /= 2;
+            twos += 1;
+        }
+        while denom % 5 == 0
+        {
+            denom /= 5;
+            fives += 1;
+        }
+        Some(twos.max(fives))
+            .filter(|&decimals| denom == 1 && decimals <= MAX_EXACT_DECIMALS)
+    }
+
+    /// Whether the number can be written exactly as a decimal number, like 60.3 but not 1/3.
+    pub fn is_decimal(self) -> bool
+    {
+        self.exact_decimals().is_some()
+    }
+
+    /// Writes the number rounded to the given decimal places, with halves rounded away from zero.
+    fn fmt_decimals(self, decimals: usize, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let Some(scale) = u32::try_from(decimals).ok().and_then(|decimals| 10i128.checked_pow(decimals))
+        else
+        {
+            return write!(f, "{number:.decimals$}", number = self.to_f64())
+        };
+        let Some(scaled) = self.numer.unsigned_abs().checked_mul(scale as u128)
+        else
+        {
+            return write!(f, "{number:.decimals$}", number = self.to_f64())
+        };
+        let denom = self.denom as u128;
+        let rounded = (scaled + denom/2)/denom;
+        // Something that rounds to zero isn't negative
+        if self.numer < 0 && rounded != 0
+        {
+            f.write_str("-")?;
+        }
+        let (whole, fraction) = (rounded/scale as u128, rounded % scale as u128);
+        write!(f, "{whole}")?;
+        if decimals > 0
+        {
+            write!(f, ".{fraction:0decimals$}")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the number as a whole number and a fraction, like "60 1/3".
+    fn fmt_fraction(self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        let denom = self.denom as u128;
+        let whole = self.numer.unsigned_abs()/denom;
+        let numer = self.numer.unsigned_abs() % denom;
+        if self.numer < 0
+        {
+            f.write_str("-")?;
+        }
+        match (whole, numer)
+        {
+            (whole, 0) => write!(f, "{whole}"),
+            (0, numer) => write!(f, "{numer}/{denom}"),
+            (whole, numer) => write!(f, "{whole} {numer}/{denom}")
+        }
+    }
+
+    /// Shows the number as a whole number and a fraction, like "60 1/3", instead of as a decimal number.
+    pub fn fraction(self) -> impl Display
+    {
+        struct Fraction(Rational);
+
+        impl Display for Fraction
+        {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+            {
+                self.0.fmt_fraction(f)
+            }
+        }
+
+        Fraction(self)
+    }
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128
+{
+    while b != 0
+    {
+        (a, b) = (b, a % b);
+    }
+    match a
+    {
+        0 => 1,
+        gcd => gcd
+    }
+}
+
+/// Multiplies two numbers without overflowing, giving the upper and lower half of the result.
+fn widening_mul(a: u128, b: u128) -> (u128, u128)
+{
+    const HALF: u32 = u128::BITS/2;
+    const LOWER: u128 = u128::MAX >> HALF;
+
+    let (a_upper, a_lower) = (a >> HALF, a & LOWER);
+    let (b_upper, b_lower) = (b >> HALF, b & LOWER);
+    let lower = a_lower*b_lower;
+    let (cross_a, cross_b) = (a_upper*b_lower, a_lower*b_upper);
+    // At most three halves added together, which still fits
+    let middle = (lower >> HALF) + (cross_a & LOWER) + (cross_b & LOWER);
+    (
+        a_upper*b_upper + (cross_a >> HALF) + (cross_b >> HALF) + (middle >> HALF),
+        (middle << HALF) | (lower & LOWER)
+    )
+}
+
+/// Written with the precision if one is given, like "{:.2}", or else exactly if it can be, and with [`Rational::DECIMALS`] places if not.
+impl Display for Rational
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match (f.precision(), self.exact_decimals())
+        {
+            (Some(decimals), _) => self.fmt_decimals(decimals, f),
+            (None, Some(decimals)) => self.fmt_decimals(decimals as usize, f),
+            (None, None) => self.fmt_decimals(Self::DECIMALS, f)
+        }
+    }
+}
+
+/// Parses decimal numbers like "42", "19.5", "-.25" or "1e-3" exactly.
+impl FromStr for Rational
+{
+    type Err = InvalidNumber;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (negative, s) = match s.strip_prefix('-')
+        {
+            Some(s) => (true, s),
+            None => (false, s.strip_prefix('+').unwrap_or(s))
+        };
+        let (mantissa, exponent) = match s.split_once(['e', 'E'])
+        {
+            Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+            None => (s, None)
+        };
+        let (whole, fraction) = mantissa.split_once('.')
+            .unwrap_or((mantissa, ""));
+        if whole.is_empty() && fraction.is_empty()
+        {
+            return Err(if s.is_empty() {InvalidNumber::Empty} else {InvalidNumber::InvalidDigit})
+        }
+
+        let mut numer: i128 = 0;
+        for c in whole.chars().chain(fraction.chars())
+        {
+            let digit = c.to_digit(10)
+                .ok_or(InvalidNumber::InvalidDigit)?;
+            numer = numer.checked_mul(10)
+                .and_then(|numer| numer.checked_add(digit.into()))
+                .ok_or(InvalidNumber::TooLarge)?;
+        }
+
+        let exponent = match exponent
+        {
+            Some(exponent) => {
+                let (negative, digits) = match exponent.strip_prefix('-')
+                {
+                    Some(digits) => (true, digits),
+                    None => (false, exponent.strip_prefix('+').unwrap_or(exponent))
+                };
+                if digits.is_empty() || !digits.chars().all(|c| c.is_ascii_digit())
+                {
+                    return Err(InvalidNumber::InvalidDigit)
+                }
+                let exponent = digits.parse::<u32>()
+                    .ok()
+                    .filter(|&exponent| exponent <= MAX_EXPONENT)
+                    .ok_or(InvalidNumber::TooLarge)? as i64;
+                if negative {-exponent} else {exponent}
+            },
+            None => 0
+        };
+        let exponent = exponent - fraction.len() as i64;
+
+        let power = 10i128.checked_pow(exponent.unsigned_abs() as u32)
+            .ok_or(InvalidNumber::TooLarge)?;
+        let number = if exponent < 0
+        {
+            Self::new(numer, power)?
+        }
+        else
+        {
+            Self::integer(numer.checked_mul(power).ok_or(InvalidNumber::TooLarge)?)
+        };
+        if negative {number.checked_neg().ok_or(InvalidNumber::TooLarge)} else {Ok(number)}
+    }
+}
+
+impl Ord for Rational
+{
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        // a/b < c/d is a*d < c*b, which is compared with twice the bits so that it's always exact
+        let sign = self.numer.signum();
+        let magnitude = || widening_mul(self.numer.unsigned_abs(), other.denom as u128)
+            .cmp(&widening_mul(other.numer.unsigned_abs(), self.denom as u128));
+        match sign.cmp(&other.numer.signum())
+        {
+            Ordering::Equal if sign < 0 => magnitude().reverse(),
+            Ordering::Equal => magnitude(),
+            ordering => ordering
+        }
+    }
+}
+impl PartialOrd for Rational
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, InvalidExpression, InvalidNumber, InvalidRead, InvalidSyntax}, rational::Rational, tests::TempFile};
+
+    #[test]
+    fn rationals() -> Result<(), Error>
+    {
+        let file = TempFile::new("rationals", "Quizzbuzz: 0.1 0.2 1/3\nKate's Bush: 0.3 0 1/3\nBlank: 10/3 1e-1 -\n");
+        let scores = |args: &[&str]| file.scores(&[args, &["--output", "csv"]].concat());
+
+        // 0.1 + 0.2 is exactly 0.3, so the first two teams share the place
+        assert_eq!(scores(&[])?, "place,team,1,2,3,total,climb\r\n1,Blank,3.33,0.1,,3.43,0\r\n2,Quizzbuzz,0.1,0.2,0.33,0.63,0\r\n2,Kate's Bush,0.3,0,0.33,0.63,0\r\n");
+        assert_eq!(scores(&["--decimals", "1"])?, "place,team,1,2,3,total,climb\r\n1,Blank,3.3,0.1,,3.4,0\r\n2,Quizzbuzz,0.1,0.2,0.3,0.6,0\r\n2,Kate's Bush,0.3,0.0,0.3,0.6,0\r\n");
+        assert_eq!(scores(&["--fractions"])?, "place,team,1,2,3,total,climb\r\n1,Blank,3 1/3,1/10,,3 13/30,0\r\n2,Quizzbuzz,1/10,1/5,1/3,19/30,0\r\n2,Kate's Bush,3/10,0,1/3,19/30,0\r\n");
+
+        // Numbers just past the smallest one are too large, and not a crash
+        for points in ["1/(-170141183460469231731687303715884105727-1)", "-170141183460469231731687303715884105727-1"]
+        {
+            file.write(&format!("Quizzbuzz: {points}\n"));
+            let error = scores(&["--fractions"]).unwrap_err();
+            assert!(matches!(error, Error::InvalidRead { error: InvalidRead::InvalidSyntax { ref errors }, .. } if matches!(errors[..], [InvalidSyntax::CannotParsePoints { error: InvalidExpression::InvalidNumber { error: InvalidNumber::TooLarge, .. }, .. }])));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_and_format() -> Result<(), InvalidNumber>
+    {
+        assert_eq!("1e-3".parse::<Rational>(), Rational::new(1, 1000));
+        assert_eq!("-.25".parse::<Rational>(), Rational::new(-1, 4));
+        assert_eq!(format!("{}", Rational::new(-1, 1000)?), "-0.001");
+        assert_eq!(format!("{:.2}", Rational::new(-1, 1000)?), "0.00");
+        assert_eq!(format!("{:.1}", Rational::new(1, 4)?), "0.3");
+        assert_eq!(format!("{}", Rational::new(-7, 3)?.fraction()), "-2 1/3");
+
+        Ok(())
+    }
+
+    #[test]
+    fn never_overflows() -> Result<(), InvalidNumber>
+    {
+        // -i128::MAX is the smallest number there is, since i128::MIN can't be made positive
+        assert_eq!(Rational::new(i128::MIN, 1), Err(InvalidNumber::TooLarge));
+        assert_eq!(Rational::new(1, i128::MIN), Err(InvalidNumber::TooLarge));
+        assert_eq!(Rational::new(i128::MIN, i128::MIN), Ok(Rational::ONE));
+        let smallest = Rational::integer(-i128::MAX);
+        assert_eq!(smallest.checked_sub(Rational::ONE), None);
+        assert_eq!(format!("{}", smallest.fraction()), "-170141183460469231731687303715884105727");
+
+        // Compared exactly, even when the numbers are too large to be multiplied together
+        let a = Rational::new(i128::MAX, i128::MAX - 1)?;
+        let b = Rational::new(i128::MAX - 1, i128::MAX - 2)?;
+        assert!(a < b);
+        assert!(b.checked_neg() < a.checked_neg());
+        assert!(smallest < Rational::ZERO && Rational::ZERO < a);
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use core::fmt::Display;
+
+pub const DIRECTIVE: char = '@';
+
+/// A line starting with '@' in the poengsum-file, which says something about the quiz rather than a team.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Directive
+{
+    Rounds,
+    TieBreak,
+    Weights
+}
+
+impl Directive
+{
+    pub const VARIANT_COUNT: usize = 3; //core::mem::variant_count::<Directive>(); TODO use this instead when stable
+    pub const VARIANTS: [Directive; Self::VARIANT_COUNT] = [Directive::Rounds, Directive::TieBreak, Directive::Weights];
+
+    pub const fn name(self) -> &'static str
+    {
+        match self
+        {
+            Self::Rounds => "rounds",
+            Self::TieBreak => "tie-break",
+            Self::Weights => "weights"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        Self::VARIANTS.into_iter()
+            .find(|directive| directive.name().eq_ignore_ascii_case(name.trim()))
+    }
+}
+
+impl Display for Directive
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.name().fmt(f)
+    }
+}
@@ -0,0 +1,90 @@
+use crate::{error::InvalidNumber, rational::Rational};
+
+pub const ABSENT: &str = "-";
+/// Written after the points of the round a team played their joker in.
+pub const JOKER: char = '!';
+/// What the points of the round a team played their joker in are multiplied by.
+pub const JOKER_FACTOR: Rational = Rational::integer(2);
+
+/// What a team got in a round.
+#[derive(PartialEq, Clone, Debug)]
+pub enum Entry
+{
+    /// The team didn't participate in the round, which is not the same as getting 0 points.
+    Absent,
+    Points {
+        points: Rational,
+        /// The arithmetic expression the points were written as, if they weren't just a number.
+        expression: Option<Box<str>>,
+        joker: bool
+    }
+}
+
+impl Entry
+{
+    pub fn points(&self) -> Option<Rational>
+    {
+        match self
+        {
+            Entry::Absent => None,
+            Entry::Points { points, expression: _, joker: _ } => Some(*points)
+        }
+    }
+
+    /// The points after the weight of the round, and the team's joker, are applied.
+    pub fn effective(&self, weight: Rational) -> Result<Option<Rational>, InvalidNumber>
+    {
+        self.points()
+            .map(|points| points.checked_mul(self.factor(weight)?).ok_or(InvalidNumber::TooLarge))
+            .transpose()
+    }
+
+    /// What the points are multiplied by, given the weight of the round.
+    pub fn factor(&self, weight: Rational) -> Result<Rational, InvalidNumber>
+    {
+        if self.joker()
+        {
+            weight.checked_mul(JOKER_FACTOR).ok_or(InvalidNumber::TooLarge)
+        }
+        else
+        {
+            Ok(weight)
+        }
+    }
+
+    pub fn joker(&self) -> bool
+    {
+        match self
+        {
+            Entry::Absent => false,
+            Entry::Points { points: _, expression: _, joker } => *joker
+        }
+    }
+
+    pub fn expression(&self) -> Option<&str>
+    {
+        match self
+        {
+            Entry::Absent => None,
+            Entry::Points { points: _, expression, joker: _ } => expression.as_deref()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn absent_is_not_zero() -> Result<(), Error>
+    {
+        let file = TempFile::new("absent", "Quizzbuzz: 3 - 5\nKate's Bush: 0 -2 -\n");
+
+        let json = file.scores(&["--output", "json"])?;
+        assert!(json.contains("\"points\": 8, \"climb\": 0, \"rounds\": [3, null, 5], \"expressions\": [null, null, null], \"played\": 2, \"tie_break\": null, \"effective\": [3, null, 5], \"counted\": [true, true, true]}"));
+        assert!(json.contains("\"points\": -2, \"climb\": 0, \"rounds\": [0, -2, null], \"expressions\": [null, null, null], \"played\": 2, \"tie_break\": null, \"effective\": [0, -2, null], \"counted\": [true, true, true]}"));
+
+        Ok(())
+    }
+}
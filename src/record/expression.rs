@@ -0,0 +1,174 @@
+use core::ops::Range;
+
+use crate::{error::{InvalidExpression, InvalidNumber}, rational::Rational};
+
+/// Evaluates simple arithmetic in a point cell, like "7+2", "10-1" or "(3+4)*1/2".
+///
+/// On failure, the range says which part of the expression is wrong.
+pub struct Expression<'a>
+{
+    src: &'a str,
+    pos: usize
+}
+
+impl<'a> Expression<'a>
+{
+    pub fn evaluate(src: &'a str) -> Result<Rational, (Range<usize>, InvalidExpression)>
+    {
+        let mut expression = Self {
+            src,
+            pos: 0
+        };
+        let value = expression.sum()?;
+        if let Some(c) = expression.peek()
+        {
+            return Err(expression.unexpected(c))
+        }
+        Ok(value)
+    }
+
+    fn peek(&self) -> Option<char>
+    {
+        self.src[self.pos..].chars().next()
+    }
+
+    fn unexpected(&self, c: char) -> (Range<usize>, InvalidExpression)
+    {
+        (self.pos..self.pos + c.len_utf8(), InvalidExpression::UnexpectedChar { c })
+    }
+
+    /// The part of the expression from the start up to here got too large to be exact.
+    fn too_large(&self, start: usize) -> (Range<usize>, InvalidExpression)
+    {
+        (start..self.pos, InvalidNumber::TooLarge.into())
+    }
+
+    fn sum(&mut self) -> Result<Rational, (Range<usize>, InvalidExpression)>
+    {
+        let start = self.pos;
+        let mut value = self.product()?;
+        loop
+        {
+            let op = match self.peek()
+            {
+                Some('+') => Rational::checked_add,
+                Some('-') => Rational::checked_sub,
+                _ => return Ok(value)
+            };
+            self.pos += 1;
+            let rhs = self.product()?;
+            value = op(value, rhs).ok_or_else(|| self.too_large(start))?
+        }
+    }
+
+    fn product(&mut self) -> Result<Rational, (Range<usize>, InvalidExpression)>
+    {
+        let start = self.pos;
+        let mut value = self.factor()?;
+        loop
+        {
+            match self.peek()
+            {
+                Some('*') => {
+                    self.pos += 1;
+                    let rhs = self.factor()?;
+                    value = value.checked_mul(rhs).ok_or_else(|| self.too_large(start))?
+                },
+                Some('/') => {
+                    self.pos += 1;
+                    let divisor_start = self.pos;
+                    let divisor = self.factor()?;
+                    if divisor.is_zero()
+                    {
+                        return Err((divisor_start..self.pos, InvalidExpression::DivisionByZero))
+                    }
+                    value = value.checked_div(divisor).ok_or_else(|| self.too_large(start))?
+                },
+                _ => return Ok(value)
+            }
+        }
+    }
+
+    fn factor(&mut self) -> Result<Rational, (Range<usize>, InvalidExpression)>
+    {
+        match self.peek()
+        {
+            Some('-') => {
+                self.pos += 1;
+                let start = self.pos - 1;
+                let value = self.factor()?;
+                value.checked_neg().ok_or_else(|| self.too_large(start))
+            },
+            Some('+') => {
+                self.pos += 1;
+                self.factor()
+            },
+            Some('(') => {
+                let open = self.pos;
+                self.pos += 1;
+                let value = self.sum()?;
+                match self.peek()
+                {
+                    Some(')') => {
+                        self.pos += 1;
+                        Ok(value)
+                    },
+                    Some(c) => Err(self.unexpected(c)),
+                    None => Err((open..open + 1, InvalidExpression::UnclosedParenthesis))
+                }
+            },
+            Some(c) if Self::is_number(c) => self.number(),
+            Some(c) => Err(self.unexpected(c)),
+            None => Err((self.pos..self.pos, InvalidExpression::ExpectedNumber))
+        }
+    }
+
+    fn is_number(c: char) -> bool
+    {
+        c.is_alphanumeric() || c == '.'
+    }
+
+    fn number(&mut self) -> Result<Rational, (Range<usize>, InvalidExpression)>
+    {
+        let start = self.pos;
+        let mut prev = None;
+        while let Some(c) = self.peek()
+        {
+            // Allow exponents like "1e-3"
+            let exponent_sign = (c == '-' || c == '+') && matches!(prev, Some('e' | 'E'))
+                && self.src[start..].starts_with(|c: char| c.is_ascii_digit() || c == '.');
+            if !Self::is_number(c) && !exponent_sign
+            {
+                break
+            }
+            self.pos += c.len_utf8();
+            prev = Some(c);
+        }
+
+        self.src[start..self.pos]
+            .parse::<Rational>()
+            .map_err(|error| (start..self.pos, error.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, ExitCode, InvalidRead, InvalidSyntax}, tests::TempFile};
+
+    #[test]
+    fn expressions() -> Result<(), Error>
+    {
+        let file = TempFile::new("expressions", "Quizzbuzz: 7+2 10-1 (1+2)/4 3\n");
+
+        let json = file.scores(&["--output", "json"])?;
+        assert!(json.contains("\"points\": 21.75, \"climb\": 0, \"rounds\": [9, 9, 0.75, 3], \"expressions\": [\"7+2\", \"10-1\", \"(1+2)/4\", null]"));
+
+        file.write("Quizzbuzz: 1 7+*2\n");
+        let error = file.scores(&[]).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::InvalidSyntax { ref errors }, .. } if matches!(errors[..], [InvalidSyntax::CannotParsePoints { ref col, .. }] if *col == (15..16))));
+        assert_eq!(error.exit_code(), ExitCode::InvalidSyntax);
+
+        Ok(())
+    }
+}
@@ -1,7 +1,12 @@
 moddef::moddef!(
     flat(pub) mod {
+        directive,
+        entry,
+        expression,
         record_parser,
+        record_writer,
         record,
-        records
+        records,
+        source
     }
-);
\ No newline at end of file
+);
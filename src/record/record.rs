@@ -1,6 +1,31 @@
+use core::ops::Range;
+
+use super::Entry;
+
 #[derive(PartialEq, Clone)]
 pub struct Record
 {
     pub team: Box<str>,
-    pub points: Vec<f64>
+    pub points: Vec<Entry>,
+    /// Where in the file the team was listed, so that it can be pointed out in warnings.
+    pub row: usize,
+    pub line: Box<str>,
+    pub team_col: Range<usize>,
+    pub cols: Vec<Range<usize>>
+}
+
+impl Record
+{
+    /// A record that wasn't read from a file, so there is nowhere to point to.
+    pub fn new(team: Box<str>, points: Vec<Entry>) -> Self
+    {
+        Self {
+            team,
+            points,
+            row: 0,
+            line: "".into(),
+            team_col: 0..0,
+            cols: Vec::new()
+        }
+    }
 }
\ No newline at end of file
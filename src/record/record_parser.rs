@@ -1,11 +1,20 @@
-use crate::error::InvalidSyntax;
+use core::ops::Range;
 
-use super::{Record, Records};
+use crate::{error::{InvalidExpression, InvalidSyntax}, rational::Rational, round::{RoundNames, Weights, WEIGHT_SEPARATOR}, scoring::{TieBreak, TIE_BREAK_SEPARATOR}};
+
+use super::{Directive, Entry, Expression, Record, Records, ABSENT, DIRECTIVE, JOKER};
+
+pub const COMMENT: char = '#';
+pub const ESCAPE: char = '\\';
 
 pub struct RecordParser
 {
     row: usize,
-    records: Vec<Record>
+    records: Vec<Record>,
+    round_names: Option<(usize, RoundNames)>,
+    tie_breaks: Option<(usize, Box<[TieBreak]>)>,
+    weights: Option<(usize, Weights)>,
+    errors: Vec<InvalidSyntax>
 }
 
 impl RecordParser
@@ -14,7 +23,11 @@ impl RecordParser
     {
         Self {
             row: 0,
-            records: Vec::new()
+            records: Vec::new(),
+            round_names: None,
+            tie_breaks: None,
+            weights: None,
+            errors: Vec::new()
         }
     }
 
@@ -23,17 +36,79 @@ impl RecordParser
         self.row
     }
 
-    pub fn parse_line(&mut self, line: Box<str>) -> Result<(), InvalidSyntax>
+    /// Where the part of the line that isn't a comment ends.
+    pub fn content_end(line: &str) -> usize
+    {
+        let mut escaped = false;
+        for (i, c) in line.char_indices()
+        {
+            match c
+            {
+                _ if escaped => escaped = false,
+                ESCAPE => escaped = true,
+                COMMENT => return i,
+                _ => ()
+            }
+        }
+        line.len()
+    }
+
+    fn unescape(team: &str) -> Box<str>
+    {
+        let mut unescaped = String::with_capacity(team.len());
+        let mut chars = team.chars().peekable();
+        while let Some(c) = chars.next()
+        {
+            if c == ESCAPE && let Some(&next) = chars.peek() && (next == COMMENT || next == ESCAPE || next == DIRECTIVE)
+            {
+                continue
+            }
+            unescaped.push(c);
+        }
+        unescaped.into_boxed_str()
+    }
+
+    /// The opposite of `unescape`, for writing a team name to the file.
+    pub fn escape(team: &str) -> Box<str>
+    {
+        let mut escaped = String::with_capacity(team.len());
+        for (i, c) in team.char_indices()
+        {
+            if c == COMMENT || c == ESCAPE || (i == 0 && c == DIRECTIVE)
+            {
+                escaped.push(ESCAPE);
+            }
+            escaped.push(c);
+        }
+        escaped.into_boxed_str()
+    }
+
+    /// Parses one line, remembering any errors in it so that the rest of the file can still be checked.
+    pub fn parse_line(&mut self, line: Box<str>)
     {
         self.row += 1;
-        if line.trim().is_empty()
+        if let Err(error) = self.parse_content(line)
+        {
+            self.errors.push(error);
+        }
+    }
+
+    fn parse_content(&mut self, line: Box<str>) -> Result<(), InvalidSyntax>
+    {
+        let content_end = Self::content_end(&line);
+        let content = &line[..content_end];
+        if content.trim().is_empty()
         {
             return Ok(())
         }
+        if content.trim_start().starts_with(DIRECTIVE)
+        {
+            return self.parse_directive(line, content_end)
+        }
         let mut col = None;
-        for i in 0..line.len()
+        for i in 0..content.len()
         {
-            if line.get(i..i + 1) == Some(":")
+            if content.get(i..i + 1) == Some(":")
             {
                 col = Some(i);
             }
@@ -47,7 +122,9 @@ impl RecordParser
             })
         };
 
-        let team = line.get(..col).map(str::trim).unwrap_or("");
+        let team = content.get(..col).map(str::trim).unwrap_or("");
+        let team_start = content.len() - content.trim_start().len();
+        let team_col = team_start..team_start + team.len();
         if team.is_empty()
         {
             return Err(InvalidSyntax::UnnamedTeam {
@@ -56,41 +133,293 @@ impl RecordParser
                 line
             })
         }
-        let team = Box::<str>::from(team);
+        let team = Self::unescape(team);
         col += 1;
-        let each_points = line.get(col..).map(str::trim).unwrap_or("");
+        let each_points = content.get(col..).map(str::trim).unwrap_or("");
 
         let mut points = Vec::new();
+        let mut cols = Vec::new();
+        let mut valid = true;
+        let mut joker_round = None;
 
         for (i, span) in each_points.split_whitespace().enumerate()
         {
             col = unsafe {
                 span.as_ptr().offset_from_unsigned(line.as_ptr())
             };
-            points.push(match span.parse::<f64>()
+            cols.push(col..col + span.len());
+            points.push(match Self::parse_points(span)
             {
-                Ok(points) => points,
-                Err(error) => return Err(InvalidSyntax::CannotParsePoints {
-                    row: self.row,
-                    col: col..(col + span.len()),
-                    line,
-                    team,
-                    round: i + 1,
-                    error
-                })
+                // Each team only has one joker to play
+                Ok(entry) if entry.joker() && let Some(first_round) = joker_round => {
+                    self.errors.push(InvalidSyntax::DuplicateJoker {
+                        row: self.row,
+                        col: (col + span.len() - JOKER.len_utf8())..(col + span.len()),
+                        line: line.clone(),
+                        team: team.clone(),
+                        round: i + 1,
+                        first_round
+                    });
+                    valid = false;
+                    continue
+                },
+                Ok(entry) => {
+                    if entry.joker()
+                    {
+                        joker_round = Some(i + 1);
+                    }
+                    entry
+                },
+                Err((bad, error)) => {
+                    self.errors.push(InvalidSyntax::CannotParsePoints {
+                        row: self.row,
+                        col: (col + bad.start)..(col + bad.end),
+                        line: line.clone(),
+                        team: team.clone(),
+                        round: i + 1,
+                        error
+                    });
+                    valid = false;
+                    continue
+                }
             });
         }
 
+        if !valid
+        {
+            return Ok(())
+        }
+
         self.records.push(Record {
             team,
-            points
+            points,
+            row: self.row,
+            line,
+            team_col,
+            cols
         });
 
         Ok(())
     }
 
-    pub fn collect(self) -> Result<Records, InvalidSyntax>
+    /// Parses the points of one team in one round, or gives the part of it that's wrong.
+    pub fn parse_points(span: &str) -> Result<Entry, (Range<usize>, InvalidExpression)>
+    {
+        if span == ABSENT
+        {
+            return Ok(Entry::Absent)
+        }
+        let (span, joker) = match span.strip_suffix(JOKER)
+        {
+            Some(span) => (span, true),
+            None => (span, false)
+        };
+        if let Ok(points) = span.parse::<Rational>()
+        {
+            return Ok(Entry::Points {
+                points,
+                expression: None,
+                joker
+            })
+        }
+        Expression::evaluate(span).map(|points| Entry::Points {
+            points,
+            expression: Some(span.into()),
+            joker
+        })
+    }
+
+    fn parse_directive(&mut self, line: Box<str>, content_end: usize) -> Result<(), InvalidSyntax>
+    {
+        let content = &line[..content_end];
+        let start = content.find(DIRECTIVE).unwrap_or(0) + DIRECTIVE.len_utf8();
+        let col = match content.find(':')
+        {
+            Some(col) => col,
+            None => return Err(InvalidSyntax::MissingColon {
+                row: self.row,
+                line
+            })
+        };
+
+        let directive = match Directive::from_name(&content[start..col])
+        {
+            Some(directive) => directive,
+            None => return Err(InvalidSyntax::UnknownDirective {
+                row: self.row,
+                col: start..col,
+                line
+            })
+        };
+
+        let first_row = match directive
+        {
+            Directive::Rounds => self.round_names.as_ref().map(|&(first_row, _)| first_row),
+            Directive::TieBreak => self.tie_breaks.as_ref().map(|&(first_row, _)| first_row),
+            Directive::Weights => self.weights.as_ref().map(|&(first_row, _)| first_row)
+        };
+        if let Some(first_row) = first_row
+        {
+            return Err(InvalidSyntax::DuplicateDirective {
+                row: self.row,
+                line,
+                directive,
+                first_row
+            })
+        }
+
+        match directive
+        {
+            Directive::Rounds => {
+
+                let mut names: Vec<Option<Box<str>>> = Vec::new();
+                let mut offset = col + 1;
+                for span in content[col + 1..].split(',')
+                {
+                    let col = offset + (span.len() - span.trim_start().len())..offset + span.trim_end().len();
+                    offset += span.len() + 1;
+
+                    let name = span.trim();
+                    if name.is_empty()
+                    {
+                        names.push(None);
+                        continue
+                    }
+                    let name = Self::unescape(name);
+
+                    // Names that look like numbers or ranges couldn't be told apart from them on the commandline
+                    if name.starts_with(|c: char| c.is_ascii_digit() || c == '+') || name.contains("..")
+                    {
+                        self.errors.push(InvalidSyntax::InvalidRoundName {
+                            row: self.row,
+                            col,
+                            line: line.clone(),
+                            name
+                        });
+                        names.push(None);
+                        continue
+                    }
+                    if names.iter().flatten().any(|other| other.eq_ignore_ascii_case(&name))
+                    {
+                        self.errors.push(InvalidSyntax::DuplicateRoundName {
+                            row: self.row,
+                            col,
+                            line: line.clone(),
+                            name
+                        });
+                        names.push(None);
+                        continue
+                    }
+                    names.push(Some(name));
+                }
+
+                self.round_names = Some((self.row, RoundNames::new(names)));
+            },
+            Directive::TieBreak => {
+                let mut tie_breaks = Vec::new();
+                let mut offset = col + 1;
+                for span in content[col + 1..].split(TIE_BREAK_SEPARATOR)
+                {
+                    let col = offset + (span.len() - span.trim_start().len())..offset + span.trim_end().len();
+                    offset += span.len() + TIE_BREAK_SEPARATOR.len_utf8();
+
+                    match TieBreak::from_name(span)
+                    {
+                        Some(tie_break) => tie_breaks.push(tie_break),
+                        None => self.errors.push(InvalidSyntax::UnknownTieBreak {
+                            row: self.row,
+                            col,
+                            line: line.clone(),
+                            name: span.trim().into()
+                        })
+                    }
+                }
+
+                self.tie_breaks = Some((self.row, tie_breaks.into_boxed_slice()));
+            },
+            Directive::Weights => {
+                let mut weights = Vec::new();
+                let mut offset = col + 1;
+                for span in content[col + 1..].split(WEIGHT_SEPARATOR)
+                {
+                    let col = offset + (span.len() - span.trim_start().len())..offset + span.trim_end().len();
+                    offset += span.len() + WEIGHT_SEPARATOR.len_utf8();
+
+                    match Weights::parse_weight(span)
+                    {
+                        Ok(weight) => weights.push(weight),
+                        Err(weight) => {
+                            self.errors.push(InvalidSyntax::InvalidWeight {
+                                row: self.row,
+                                col,
+                                line: line.clone(),
+                                weight
+                            });
+                            weights.push(None);
+                        }
+                    }
+                }
+
+                self.weights = Some((self.row, Weights::new(weights)));
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn collect(self) -> Result<Records, Box<[InvalidSyntax]>>
+    {
+        if !self.errors.is_empty()
+        {
+            return Err(self.errors.into_boxed_slice())
+        }
+
+        let round_names = self.round_names
+            .map(|(_, round_names)| round_names)
+            .unwrap_or_default();
+        let tie_breaks = self.tie_breaks
+            .map(|(_, tie_breaks)| tie_breaks)
+            .unwrap_or_default();
+        let weights = self.weights
+            .map(|(_, weights)| weights)
+            .unwrap_or_default();
+        Records::collect_from(self.records, round_names, tie_breaks, weights)
+            .map_err(|error| [error].into())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, ExitCode, InvalidRead, InvalidSyntax}, tests::TempFile};
+
+    #[test]
+    fn comments_are_ignored() -> Result<(), Error>
+    {
+        let file = TempFile::new("comments", "# Late arrivals\n\nTable \\#7: 3 5 # Joined after the break\n   # 2 3\nBack\\\\slash: 1\n");
+
+        assert_eq!(file.scores(&["--output", "csv"])?, "place,team,1,2,total,climb\r\n1,Table #7,3,5,8,0\r\n2,Back\\slash,1,,1,0\r\n");
+
+        file.write("Table #7: 3 5\n");
+        let error = file.scores(&[]).unwrap_err();
+        assert_eq!(error.exit_code(), ExitCode::InvalidSyntax);
+
+        Ok(())
+    }
+
+    #[test]
+    fn all_syntax_errors()
     {
-        Records::collect_from(self.records)
+        let file = TempFile::new("syntax-errors", "Quizzbuzz 3 5\nKate's Bush: x 2 y\nBlank: 1 2\n: 4\n");
+
+        let error = file.scores(&[]).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::InvalidSyntax { ref errors }, .. } if matches!(errors[..], [
+            InvalidSyntax::MissingColon { row: 1, .. },
+            InvalidSyntax::CannotParsePoints { row: 2, round: 1, .. },
+            InvalidSyntax::CannotParsePoints { row: 2, round: 3, .. },
+            InvalidSyntax::UnnamedTeam { row: 4, .. }
+        ])));
+        assert_eq!(error.exit_code(), ExitCode::InvalidSyntax);
+        assert!(error.to_string().contains("Found 4 errors in"));
     }
 }
\ No newline at end of file
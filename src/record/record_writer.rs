@@ -0,0 +1,83 @@
+use std::path::Path;
+
+use crate::error::{InvalidIO, InvalidRead};
+
+use super::{Records, RecordParser, ABSENT};
+
+/// Adds a round to the poengsum-file, leaving everything else in it, like comments and spacing, as it was.
+pub struct RecordWriter<'a>
+{
+    file: &'a Path,
+    records: &'a Records
+}
+
+impl<'a> RecordWriter<'a>
+{
+    pub fn new(file: &'a Path, records: &'a Records) -> Self
+    {
+        Self {
+            file,
+            records
+        }
+    }
+
+    /// Writes the points of each team in the given round, in the same order as the records, or nothing for the teams that are `None`.
+    /// New teams are added at the end of the file.
+    pub fn add_round(&self, round: usize, points: &[Option<Box<str>>], new_teams: &[(Box<str>, Box<str>)]) -> Result<(), InvalidRead>
+    {
+        let text = std::fs::read_to_string(self.file)
+            .map_err(|io_error| InvalidRead::InvalidIO {
+                io_error,
+                error: InvalidIO::Open
+            })?;
+
+        let mut cells = vec![None; text.lines().count()];
+        for (record, points) in self.records.iter().zip(points.iter())
+        {
+            if let Some(points) = points && let Some(cell) = cells.get_mut(record.row - 1)
+            {
+                // Rounds the team is missing before this one are filled in, so that the points end up in the right column
+                let missing = round.saturating_sub(record.points.len());
+                *cell = Some(format!("{absent} {points}", absent = format!(" {ABSENT}").repeat(missing)));
+            }
+        }
+
+        let mut written = String::with_capacity(text.len());
+        for (line, cell) in text.split_inclusive('\n').zip(cells)
+        {
+            let Some(cell) = cell
+            else
+            {
+                written += line;
+                continue
+            };
+
+            let content = line.trim_end_matches(['\r', '\n']);
+            let end = content[..RecordParser::content_end(content)].trim_end().len();
+            written += &line[..end];
+            written += &cell;
+            written += &line[end..];
+        }
+
+        let newline = if text.contains("\r\n") {"\r\n"} else {"\n"};
+        for (team, points) in new_teams
+        {
+            if !written.is_empty() && !written.ends_with('\n')
+            {
+                written += newline;
+            }
+            let absent = format!(" {ABSENT}").repeat(round);
+            written += &format!("{team}:{absent} {points}{newline}", team = RecordParser::escape(team));
+        }
+
+        // Written to the side first, so that the file isn't left half-written if something goes wrong
+        let mut temporary = self.file.as_os_str().to_owned();
+        temporary.push(".tmp");
+        std::fs::write(&temporary, written)
+            .and_then(|()| std::fs::rename(&temporary, self.file))
+            .map_err(|io_error| InvalidRead::InvalidIO {
+                io_error,
+                error: InvalidIO::Write
+            })
+    }
+}
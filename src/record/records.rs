@@ -1,13 +1,52 @@
 use std::{fs::File, io::{BufRead, BufReader}};
 
-use crate::{error::{InvalidIO, InvalidRead, InvalidSyntax}, run::Run};
+use crate::{error::{Error, InvalidIO, InvalidRead, InvalidSyntax, Warning}, rational::Rational, round::{RoundNames, Weights}, scoring::TieBreak};
 
-use super::{Record, RecordParser};
+use super::{Entry, Record, RecordParser, Source};
+
+const MIN_OTHERS_PLAYED: usize = 3;
+/// How many times the spread of the others' points above their median that points have to be to be far above them.
+const FAR_ABOVE_SPREADS: i128 = 5;
+
+/// The median of the points, and how spread out they are, which is the median distance from the median, but at least half of the median.
+///
+/// Gives nothing if there are no points, or if they are too large to compare exactly.
+fn median_and_spread(points: &[Rational]) -> Option<(Rational, Rational)>
+{
+    fn median(points: &mut [Rational]) -> Option<Rational>
+    {
+        if points.is_empty()
+        {
+            return None
+        }
+        points.sort();
+        let middle = points.len()/2;
+        if points.len() % 2 == 1
+        {
+            Some(points[middle])
+        }
+        else
+        {
+            points[middle - 1].checked_add(points[middle])?.checked_div(Rational::integer(2))
+        }
+    }
+
+    let middle = median(&mut points.to_vec())?;
+    let mut distances = points.iter()
+        .map(|&points| points.checked_sub(middle).and_then(Rational::checked_abs))
+        .collect::<Option<Vec<_>>>()?;
+    let spread = median(&mut distances)?
+        .max(middle.checked_abs()?.checked_div(Rational::integer(2))?);
+    Some((middle, spread))
+}
 
 #[derive(Clone)]
 pub struct Records
 {
-    records: Vec<Record>
+    records: Vec<Record>,
+    round_names: RoundNames,
+    tie_breaks: Box<[TieBreak]>,
+    weights: Weights
 }
 
 impl Records
@@ -20,7 +59,128 @@ impl Records
             .and_then(|runde| runde.checked_sub(1))
     }
 
-    pub fn collect_from(records: impl IntoIterator<Item = Record>) -> Result<Self, InvalidSyntax>
+    pub fn iter(&self) -> core::slice::Iter<'_, Record>
+    {
+        self.records.iter()
+    }
+
+    pub fn round_names(&self) -> &RoundNames
+    {
+        &self.round_names
+    }
+
+    pub fn tie_breaks(&self) -> &[TieBreak]
+    {
+        &self.tie_breaks
+    }
+
+    pub fn weights(&self) -> &Weights
+    {
+        &self.weights
+    }
+
+    /// Looks for things that are allowed, but probably mistakes.
+    pub fn lint(&self) -> Box<[Warning]>
+    {
+        let mut warnings = Vec::new();
+
+        // Case and whitespace are ignored when comparing names
+        let simplify = |team: &str| team.chars()
+            .filter(|c| !c.is_whitespace())
+            .flat_map(char::to_lowercase)
+            .collect::<String>();
+
+        for (i, record) in self.records.iter().enumerate()
+        {
+            let Record { team, points, row, line, team_col, cols } = record;
+
+            if let Some(first) = self.records[..i].iter().find(|other| other.team == *team)
+            {
+                warnings.push(Warning::DuplicateTeam {
+                    row: *row,
+                    col: team_col.clone(),
+                    line: line.clone(),
+                    team: team.clone(),
+                    first_row: first.row
+                });
+            }
+            else if let Some(other) = self.records[..i].iter().find(|other| simplify(&other.team) == simplify(team))
+            {
+                warnings.push(Warning::SimilarTeams {
+                    row: *row,
+                    col: team_col.clone(),
+                    line: line.clone(),
+                    team: team.clone(),
+                    other: other.team.clone(),
+                    other_row: other.row
+                });
+            }
+
+            let others = self.records.iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, other)| other.points.len())
+                .max();
+            if let Some(others) = others && points.len() > others
+            {
+                warnings.push(Warning::ExtraRounds {
+                    row: *row,
+                    col: cols[others].start..cols[points.len() - 1].end,
+                    line: line.clone(),
+                    team: team.clone(),
+                    rounds: points.len(),
+                    others
+                });
+            }
+
+            for (round, (entry, col)) in points.iter().zip(cols.iter()).enumerate()
+            {
+                let Some(points) = entry.points()
+                else
+                {
+                    continue
+                };
+
+                if points < Rational::ZERO
+                {
+                    warnings.push(Warning::NegativePoints {
+                        row: *row,
+                        col: col.clone(),
+                        line: line.clone(),
+                        team: team.clone(),
+                        round: round + 1,
+                        points
+                    });
+                }
+
+                // Far more than what the others usually got is probably a typo, but only if enough others played to tell
+                let others = self.records.iter()
+                    .enumerate()
+                    .filter(|&(j, _)| j != i)
+                    .filter_map(|(_, other)| other.points.get(round).and_then(Entry::points))
+                    .collect::<Vec<_>>();
+                if others.len() >= MIN_OTHERS_PLAYED
+                    && let Some((median, spread)) = median_and_spread(&others)
+                    && spread > Rational::ZERO
+                    && spread.checked_mul(Rational::integer(FAR_ABOVE_SPREADS)).and_then(|spread| spread.checked_add(median)).is_some_and(|threshold| points > threshold)
+                {
+                    warnings.push(Warning::FarAbove {
+                        row: *row,
+                        col: col.clone(),
+                        line: line.clone(),
+                        team: team.clone(),
+                        round: round + 1,
+                        points,
+                        median
+                    });
+                }
+            }
+        }
+
+        warnings.into_boxed_slice()
+    }
+
+    pub fn collect_from(records: impl IntoIterator<Item = Record>, round_names: RoundNames, tie_breaks: Box<[TieBreak]>, weights: Weights) -> Result<Self, InvalidSyntax>
     {
         let records = records.into_iter()
             .collect::<Vec<_>>();
@@ -31,21 +191,139 @@ impl Records
         }
 
         Ok(Self {
-            records
+            records,
+            round_names,
+            tie_breaks,
+            weights
         })
     }
 
-    pub fn read(run: &Run) -> Result<Self, InvalidRead>
+    pub fn read(source: &Source) -> Result<Self, InvalidRead>
+    {
+        match source
+        {
+            Source::File(file_path) => {
+                let file = File::open(file_path)
+                    .map_err(|io_error| InvalidRead::InvalidIO {
+                        io_error,
+                        error: InvalidIO::Open
+                    })?;
+                Self::parse(BufReader::new(file))
+            },
+            Source::Stdin => Self::parse(std::io::stdin().lock())
+        }
+    }
+
+    /// Puts the rounds of each file after the rounds of the files before it, with the teams matched by name.
+    /// Rounds with the same name in two files are the same round, so a team can't have points for it in both.
+    /// The tie-breaks are those of the first file that has any, and a round keeps the weight of the first file that gives it one.
+    pub fn merge(mut files: Vec<(&Source, Records)>) -> Result<Self, Error>
     {
-        let file_path = run.file_path();
-        let file = File::open(file_path)
-            .map_err(|io_error| InvalidRead::InvalidIO {
-                io_error,
-                error: InvalidIO::Open
-            })?;
-        
-        let reader = BufReader::new(file);
+        if files.len() == 1 && let Some((_, records)) = files.pop()
+        {
+            return Ok(records)
+        }
+
+        let mut names: Vec<Option<Box<str>>> = Vec::new();
+        let mut weights: Vec<Option<Rational>> = Vec::new();
+        let mut records: Vec<Record> = Vec::new();
+        // Which file, team and round the points in each round came from, so that conflicts can point to both
+        let mut origins: Vec<Vec<Option<(usize, usize, usize)>>> = Vec::new();
+
+        for (f, (source, file)) in files.iter().enumerate()
+        {
+            let rounds = file.final_round().map_or(0, |round| round + 1);
+            let columns = (0..rounds).map(|round| {
+                let name = file.round_names.name(round);
+                let same = name.and_then(|name| names.iter()
+                    .position(|other| other.as_deref().is_some_and(|other| other.eq_ignore_ascii_case(name)))
+                );
+                let col = same.unwrap_or_else(|| {
+                    names.push(name.map(Into::into));
+                    weights.push(None);
+                    names.len() - 1
+                });
+                weights[col] = weights[col].or(file.weights.given(round));
+                col
+            }).collect::<Vec<_>>();
+
+            // A team listed twice in the same file is left as it is, like with only one file
+            let before = records.len();
+            let mut conflicts = Vec::new();
+            for (r, record) in file.iter().enumerate()
+            {
+                let i = match records[..before].iter().position(|other| other.team == record.team)
+                {
+                    Some(i) => i,
+                    None => {
+                        records.push(Record {
+                            points: Vec::new(),
+                            ..record.clone()
+                        });
+                        origins.push(Vec::new());
+                        records.len() - 1
+                    }
+                };
+
+                for (round, entry) in record.points.iter().enumerate()
+                {
+                    let col = columns[round];
+                    if records[i].points.len() <= col
+                    {
+                        records[i].points.resize(col + 1, Entry::Absent);
+                        origins[i].resize(col + 1, None);
+                    }
+                    if *entry == Entry::Absent
+                    {
+                        continue
+                    }
 
+                    if let Some((first_f, first_r, first_round)) = origins[i][col]
+                    {
+                        let (first_file, first) = &files[first_f];
+                        let first = &first.records[first_r];
+                        conflicts.push(InvalidSyntax::ConflictingPoints {
+                            row: record.row,
+                            col: record.cols[round].clone(),
+                            line: record.line.clone(),
+                            team: record.team.clone(),
+                            round: names[col].clone().unwrap_or_else(|| (col + 1).to_string().into_boxed_str()),
+                            first_file: Box::new((*first_file).clone()),
+                            first_row: first.row,
+                            first_col: first.cols[first_round].clone(),
+                            first_line: first.line.clone()
+                        });
+                        continue
+                    }
+                    records[i].points[col] = entry.clone();
+                    origins[i][col] = Some((f, r, round));
+                }
+            }
+
+            if !conflicts.is_empty()
+            {
+                return Err(InvalidRead::InvalidSyntax {
+                    errors: conflicts.into_boxed_slice()
+                }.at((*source).clone()))
+            }
+        }
+
+        let tie_breaks = files.iter()
+            .map(|(_, file)| &file.tie_breaks)
+            .find(|tie_breaks| !tie_breaks.is_empty())
+            .cloned()
+            .unwrap_or_default();
+
+        Ok(Self {
+            records,
+            round_names: RoundNames::new(names),
+            tie_breaks,
+            weights: Weights::new(weights)
+        })
+    }
+
+    fn parse(reader: impl BufRead) -> Result<Self, InvalidRead>
+    {
         let mut parser = RecordParser::new();
         for line in reader.lines()
         {
@@ -55,10 +333,13 @@ impl Records
                         row: parser.row()
                     }
                 })?.into_boxed_str();
-            parser.parse_line(line)?
+            parser.parse_line(line);
         }
 
-        Ok(parser.collect()?)
+        parser.collect()
+            .map_err(|errors| InvalidRead::InvalidSyntax {
+                errors
+            })
     }
 }
 
@@ -71,4 +352,71 @@ impl IntoIterator for Records
     {
         self.records.into_iter()
     }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, ExitCode, InvalidRead, InvalidSyntax, Warning}, tests::{self, TempFile}};
+
+    #[test]
+    fn warnings() -> Result<(), Error>
+    {
+        let file = TempFile::new("warnings", "Quizzbuzz: 3 5 15\nQuizz Buzz: 2 -1 14\nKate's Bush: 4 40 12 1\nQuizzbuzz: 1 2 3\n");
+
+        let csv = file.scores(&["--output", "csv"])?;
+        assert!(csv.starts_with("place,team,1,2,3,4,total,climb\r\n1,Kate's Bush,4,40,12,1,57,0\r\n"));
+
+        let error = file.scores(&["--strict"]).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::Strict { ref warnings }, .. } if matches!(warnings[..], [
+            Warning::SimilarTeams { row: 2, other_row: 1, .. },
+            Warning::NegativePoints { row: 2, round: 2, .. },
+            Warning::ExtraRounds { row: 3, rounds: 4, others: 3, .. },
+            Warning::FarAbove { row: 3, round: 2, .. },
+            Warning::DuplicateTeam { row: 4, first_row: 1, .. }
+        ])));
+        assert_eq!(error.exit_code(), ExitCode::InvalidSyntax);
+
+        Ok(())
+    }
+
+    #[test]
+    fn usual_points_are_not_far_above() -> Result<(), Error>
+    {
+        // Blank won the third round by a lot, but not so much that it's probably a typo
+        let file = TempFile::new("far-above", "Quizzbuzz: 2 8 2\nKate's Bush: 2 9 2\nBlank: 2 3 5\nTable \\#7: 1 7 1\n");
+        file.scores(&["--strict"])?;
+
+        // A missing decimal point still is
+        file.write("Quizzbuzz: 2 8 2\nKate's Bush: 2 9 2\nBlank: 2 3 5\nTable \\#7: 1 75 1\n");
+        let error = file.scores(&["--strict"]).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::Strict { ref warnings }, .. } if matches!(warnings[..], [Warning::FarAbove { row: 4, round: 2, .. }])));
+
+        Ok(())
+    }
+
+    #[test]
+    fn several_files() -> Result<(), Error>
+    {
+        let music = TempFile::new("music", "@rounds: Music\nQuizzbuzz: 3\nKate's Bush: 4\n");
+        let geography = TempFile::new("geography", "@rounds: Geography\nKate's Bush: 5\nBlank: 1\n");
+
+        let scores = || -> Result<String, Error> {
+            Ok(tests::run(&["--file", music.path(), "--file", geography.path(), "--output", "csv"])?.scores()?.to_string())
+        };
+
+        assert_eq!(scores()?, "place,team,Music,Geography,total,climb\r\n1,Kate's Bush,4,5,9,0\r\n2,Quizzbuzz,3,,3,0\r\n3,Blank,,1,1,0\r\n");
+
+        // The same round in both files
+        geography.write("@rounds: Geography, music\nKate's Bush: 5 2\n");
+        let error = scores().unwrap_err();
+        let Error::InvalidRead { error: InvalidRead::InvalidSyntax { ref errors }, .. } = error
+        else
+        {
+            panic!("expected a conflict, got {error}")
+        };
+        assert!(matches!(**errors, [InvalidSyntax::ConflictingPoints { row: 2, first_row: 3, .. }]));
+
+        Ok(())
+    }
 }
\ No newline at end of file
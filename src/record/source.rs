@@ -0,0 +1,50 @@
+use core::fmt::Display;
+use std::{borrow::Cow, path::Path};
+
+/// Given as the path to "--file" to read the poengsum-file from standard input instead.
+pub const STDIN: &str = "-";
+
+/// Where the poengsum-file is read from.
+#[derive(Clone, Debug)]
+pub enum Source
+{
+    File(Cow<'static, Path>),
+    Stdin
+}
+
+impl Source
+{
+    /// Gives `None` for standard input, which can't be written to or watched.
+    pub fn path(&self) -> Option<&Path>
+    {
+        match self
+        {
+            Self::File(path) => Some(path),
+            Self::Stdin => None
+        }
+    }
+}
+
+impl From<Cow<'static, Path>> for Source
+{
+    fn from(path: Cow<'static, Path>) -> Self
+    {
+        if path.as_os_str() == STDIN
+        {
+            return Self::Stdin
+        }
+        Self::File(path)
+    }
+}
+
+impl Display for Source
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            Self::File(path) => path.display().fmt(f),
+            Self::Stdin => "<stdin>".fmt(f)
+        }
+    }
+}
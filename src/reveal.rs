@@ -0,0 +1,116 @@
+use std::{io::{BufRead, Write}, time::Duration};
+
+use crate::{error::Error, rational::Rational, score::Scores, style, terminal};
+
+/// The places that are held back with "--podium".
+pub const PODIUM: usize = 3;
+
+/// Shows the leaderboard one place at a time, for reading the results out loud.
+#[derive(Clone, Copy)]
+pub struct Reveal
+{
+    pub delay: Option<Duration>,
+    pub podium: bool
+}
+
+impl Reveal
+{
+    pub fn new() -> Self
+    {
+        Self {
+            delay: None,
+            podium: false
+        }
+    }
+
+    /// Waits for the next line of input, or does nothing if there is no more input.
+    fn wait_for_key(input: &mut impl BufRead, presses: &mut usize) -> Result<(), Error>
+    {
+        *presses += 1;
+        terminal::read_line(input, *presses)?;
+        Ok(())
+    }
+
+    fn wait(&self, input: &mut impl BufRead, presses: &mut usize) -> Result<(), Error>
+    {
+        match self.delay
+        {
+            Some(delay) => {
+                std::thread::sleep(delay);
+                Ok(())
+            },
+            None => Self::wait_for_key(input, presses)
+        }
+    }
+
+    pub fn present(&self, scores: &Scores, mut input: impl BufRead, mut output: impl Write) -> Result<(), Error>
+    {
+        if self.delay.is_none() || self.podium
+        {
+            terminal::show(&mut output, format_args!("{prompt}\n", prompt = style::info("Press Enter to reveal the next place.")));
+        }
+
+        let ordered = scores.ordered();
+        let mut podium = Vec::new();
+        let mut presses = 0;
+
+        // Teams that share a place are revealed together
+        for group in ordered.chunk_by(|a, b| a.place == b.place)
+        {
+            if self.podium && group.iter().all(|score| score.place <= Rational::integer(PODIUM as i128))
+            {
+                podium.push(group);
+                continue
+            }
+
+            self.wait(&mut input, &mut presses)?;
+            for score in group
+            {
+                terminal::show(&mut output, format_args!("{score}\n", score = score.line(scores.notation())));
+            }
+        }
+
+        if !podium.is_empty()
+        {
+            Self::wait_for_key(&mut input, &mut presses)?;
+            for score in podium.into_iter().flatten()
+            {
+                terminal::show(&mut output, format_args!("{score}\n", score = score.line(scores.notation())));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, ExitCode, InvalidIO, InvalidRead}, reveal::Reveal, tests::TempFile};
+
+    #[test]
+    fn reveal() -> Result<(), Error>
+    {
+        let file = TempFile::new("reveal", "Quizzbuzz: 1\nKate's Bush: 2\nBlank: 2\nTable 7: 3\nBold: 5\nNerds: 4\n");
+        let scores = file.run(&["--reveal"])?.scores()?;
+
+        // Only two presses, so the podium is revealed when the input runs out
+        let reveal = Reveal {
+            delay: None,
+            podium: true
+        };
+        let mut output = Vec::new();
+        reveal.present(&scores, "\n\n".as_bytes(), &mut output)?;
+        let output = String::from_utf8(output).unwrap();
+
+        let teams = ["Quizzbuzz", "Blank", "Kate's Bush", "Table 7", "Nerds", "Bold"].map(|team| output.find(team).unwrap());
+        assert!(teams.is_sorted());
+
+        // Input that isn't text is an error, not a press
+        let error = reveal.present(&scores, &b"\n\xff\n"[..], Vec::new()).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::InvalidIO { error: InvalidIO::Read { row: 2 }, .. }, .. }));
+        assert_eq!(error.exit_code(), ExitCode::InvalidIO);
+
+        Ok(())
+    }
+}
@@ -21,12 +21,12 @@ impl BoundedRound
         Self::One(start)
     }
 
-    pub fn index<T>(self, slice: &[T]) -> Option<&[T]>
+    pub fn indices(self) -> core::ops::RangeInclusive<usize>
     {
         match self
         {
-            BoundedRound::One(runde) => slice.get(runde).map(core::slice::from_ref),
-            BoundedRound::Range { start, end } => slice.get(start..=end)
+            BoundedRound::One(runde) => runde..=runde,
+            BoundedRound::Range { start, end } => start..=end
         }
     }
 
@@ -56,4 +56,10 @@ impl BoundedRounds
     {
         self.rounds.iter()
     }
+
+    pub fn indices(&self) -> impl Iterator<Item = usize> + '_
+    {
+        self.iter()
+            .flat_map(|&round| round.indices())
+    }
 }
\ No newline at end of file
@@ -0,0 +1,129 @@
+use core::fmt::Display;
+
+use crate::rational::Rational;
+
+/// Separates the name of a count from its number, like "best-5".
+pub const COUNT_SEPARATOR: char = '-';
+
+/// Which of the rounds a team played count towards their total.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Count
+{
+    All,
+    /// Only the rounds where the team got the most points.
+    Best(usize),
+    /// All but the rounds where the team got the least points, where not participating is the least.
+    Drop(usize),
+    /// Only the rounds the team participated in, with the rest shown as discarded.
+    Present
+}
+
+impl Count
+{
+    pub const ALL: &str = "all";
+    pub const BEST: &str = "best";
+    pub const DROP: &str = "drop";
+    pub const PRESENT: &str = "present";
+
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        let name = name.trim();
+        if name.eq_ignore_ascii_case(Self::ALL)
+        {
+            return Some(Self::All)
+        }
+        if name.eq_ignore_ascii_case(Self::PRESENT)
+        {
+            return Some(Self::Present)
+        }
+        let (kind, n) = name.split_once(COUNT_SEPARATOR)?;
+        let n = n.trim().parse().ok()?;
+        if kind.eq_ignore_ascii_case(Self::BEST) && n > 0
+        {
+            Some(Self::Best(n))
+        }
+        else if kind.eq_ignore_ascii_case(Self::DROP)
+        {
+            Some(Self::Drop(n))
+        }
+        else
+        {
+            None
+        }
+    }
+
+    /// Which of the rounds count towards the total, given the points the team got in each of them.
+    pub fn counted(self, rounds: &[Option<Rational>]) -> Box<[bool]>
+    {
+        // The rounds from the most points to the least, with the rounds the team didn't participate in last
+        let ranked = || {
+            let mut ranked = (0..rounds.len()).collect::<Vec<_>>();
+            ranked.sort_by(|&a, &b| match (rounds[a], rounds[b])
+            {
+                (Some(a), Some(b)) => b.cmp(&a),
+                (a, b) => b.is_some().cmp(&a.is_some())
+            });
+            ranked
+        };
+        let mut counted = vec![false; rounds.len()];
+        match self
+        {
+            Count::All => counted.fill(true),
+            Count::Best(best) => for round in ranked().into_iter()
+                .take(best)
+                .filter(|&round| rounds[round].is_some())
+            {
+                counted[round] = true;
+            },
+            Count::Drop(drop) => for round in ranked().into_iter()
+                .take(rounds.len().saturating_sub(drop))
+            {
+                counted[round] = true;
+            },
+            Count::Present => for (counted, points) in counted.iter_mut().zip(rounds)
+            {
+                *counted = points.is_some();
+            }
+        }
+        counted.into_boxed_slice()
+    }
+}
+
+impl Display for Count
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        match self
+        {
+            Count::All => Self::ALL.fmt(f),
+            Count::Best(best) => write!(f, "{kind}{COUNT_SEPARATOR}{best}", kind = Self::BEST),
+            Count::Drop(drop) => write!(f, "{kind}{COUNT_SEPARATOR}{drop}", kind = Self::DROP),
+            Count::Present => Self::PRESENT.fmt(f)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn counts() -> Result<(), Error>
+    {
+        let file = TempFile::new("counts", "Quizzbuzz: 5 3 - 4\nKate's Bush: 3 5 2 1\n");
+        let scores = |count: &str, format: &str| file.scores(&["--count", count, "--output", format]);
+
+        assert_eq!(scores("all", "csv")?, "place,team,1,2,3,4,total,climb\r\n1,Quizzbuzz,5,3,,4,12,1\r\n2,Kate's Bush,3,5,2,1,11,-1\r\n");
+        assert_eq!(scores("best-2", "csv")?, "place,team,1,2,3,4,total,climb\r\n1,Quizzbuzz,5,3,,4,9,0\r\n2,Kate's Bush,3,5,2,1,8,-1\r\n");
+        assert_eq!(scores("drop-1", "csv")?, "place,team,1,2,3,4,total,climb\r\n1,Quizzbuzz,5,3,,4,12,0\r\n2,Kate's Bush,3,5,2,1,10,-1\r\n");
+
+        let json = scores("drop-1", "json")?;
+        assert!(json.contains("\"rounds\": [5, 3, null, 4], \"expressions\": [null, null, null, null], \"played\": 3, \"tie_break\": null, \"effective\": [5, 3, null, 4], \"counted\": [true, true, false, true]}"));
+        assert!(json.contains("\"rounds\": [3, 5, 2, 1], \"expressions\": [null, null, null, null], \"played\": 4, \"tie_break\": null, \"effective\": [3, 5, 2, 1], \"counted\": [true, true, true, false]}"));
+        let json = scores("present", "json")?;
+        assert!(json.contains("\"counted\": [true, true, false, true]}"));
+
+        Ok(())
+    }
+}
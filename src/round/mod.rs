@@ -2,8 +2,12 @@ moddef::moddef!(
     flat(pub) mod {
         bounded_round,
         bounded_rounds,
+        count,
         round,
+        round_id,
+        round_names,
         rounds,
-        some_rounds
+        some_rounds,
+        weights
     }
 );
\ No newline at end of file
@@ -1,37 +1,48 @@
 use crate::error::InsufficientData;
 
-use super::BoundedRound;
+use super::{BoundedRound, RoundId, RoundNames};
 
-#[derive(Clone, Copy)]
+#[derive(Clone)]
 pub enum Round
 {
-    One(usize),
+    One(RoundId),
     Range {
-        start: Option<usize>,
-        end: Option<usize>
+        start: Option<RoundId>,
+        end: Option<RoundId>
     }
 }
 
 impl Round
 {
-    pub fn bound(self, final_round: Option<usize>) -> Result<BoundedRound, InsufficientData>
+    pub fn bound(self, final_round: Option<usize>, names: &RoundNames) -> Result<BoundedRound, InsufficientData>
     {
         let final_round = final_round.ok_or(InsufficientData::NoRoundsYet)?;
 
-        let check = |round: usize| if round <= final_round
-        {
-            Ok(round)
-        }
-        else
-        {
-            Err(InsufficientData::RoundNotYet { round, final_round })
+        let check = |round: RoundId| {
+            let no = match round
+            {
+                RoundId::No(no) => no,
+                RoundId::Name(name) => names.find(&name)
+                    .ok_or_else(|| InsufficientData::UnknownRound {
+                        name,
+                        names: names.iter().map(Into::into).collect()
+                    })?
+            };
+            if no <= final_round
+            {
+                Ok(no)
+            }
+            else
+            {
+                Err(InsufficientData::RoundNotYet { round: no, name: names.name(no).map(Into::into), final_round })
+            }
         };
 
         match self
         {
             Round::One(round) => Ok(BoundedRound::One(check(round)?)),
             Round::Range { start, end } => {
-                let check_or = |round: Option<usize>, or: usize| match round
+                let check_or = |round: Option<RoundId>, or: usize| match round
                 {
                     Some(round) => check(round),
                     None => Ok(or)
@@ -44,4 +55,4 @@ impl Round
             },
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,18 @@
+#[derive(Clone, Debug)]
+pub enum RoundId
+{
+    No(usize),
+    Name(Box<str>)
+}
+
+impl RoundId
+{
+    pub fn no(&self) -> Option<usize>
+    {
+        match self
+        {
+            RoundId::No(round) => Some(*round),
+            RoundId::Name(_) => None
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::borrow::Cow;
+
+#[derive(Clone, Default, Debug)]
+pub struct RoundNames
+{
+    names: Vec<Option<Box<str>>>
+}
+
+impl RoundNames
+{
+    pub fn new(names: impl IntoIterator<Item = Option<Box<str>>>) -> Self
+    {
+        Self {
+            names: names.into_iter().collect()
+        }
+    }
+
+    pub fn name(&self, round: usize) -> Option<&str>
+    {
+        self.names.get(round)?.as_deref()
+    }
+
+    /// The name of the round if it has one, otherwise its number.
+    pub fn label(&self, round: usize) -> Cow<'_, str>
+    {
+        match self.name(round)
+        {
+            Some(name) => name.into(),
+            None => (round + 1).to_string().into()
+        }
+    }
+
+    pub fn find(&self, name: &str) -> Option<usize>
+    {
+        let name = name.trim();
+        self.names.iter()
+            .position(|other| other.as_deref().is_some_and(|other| other.eq_ignore_ascii_case(name)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &str>
+    {
+        self.names.iter()
+            .flatten()
+            .map(|name| &**name)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, InsufficientData}, tests::TempFile};
+
+    #[test]
+    fn named_rounds() -> Result<(), Error>
+    {
+        let file = TempFile::new("named-rounds", "@rounds: Music, Geography, Picture round\nQuizzbuzz: 3 5\n\\@home: 4 2\n");
+
+        assert_eq!(file.scores(&["--output", "csv"])?, "place,team,Music,Geography,total,climb\r\n1,Quizzbuzz,3,5,8,1\r\n2,@home,4,2,6,-1\r\n");
+        assert_eq!(file.scores(&["geography", "1", "--output", "csv"])?, "place,team,Geography,Music,total,climb\r\n1,Quizzbuzz,5,3,8,0\r\n2,@home,2,4,6,0\r\n");
+        assert_eq!(file.scores(&["..Geography"])?, file.scores(&["1..2"])?);
+
+        assert!(matches!(file.scores(&["Picture round"]), Err(Error::InsufficientData { error: InsufficientData::RoundNotYet { round: 2, .. } })));
+        assert!(matches!(file.scores(&["Sports"]), Err(Error::InsufficientData { error: InsufficientData::UnknownRound { .. } })));
+
+        Ok(())
+    }
+}
@@ -1,7 +1,8 @@
 use crate::error::InsufficientData;
 
-use super::{BoundedRound, BoundedRounds, Round, SomeRounds};
+use super::{BoundedRound, BoundedRounds, Round, RoundNames, SomeRounds};
 
+#[derive(Clone)]
 pub enum Rounds
 {
     All,
@@ -22,7 +23,7 @@ impl Rounds
         }
     }
 
-    pub fn bound(self, final_round: Option<usize>) -> Result<BoundedRounds, InsufficientData>
+    pub fn bound(self, final_round: Option<usize>, names: &RoundNames) -> Result<BoundedRounds, InsufficientData>
     {
         match self
         {
@@ -31,7 +32,7 @@ impl Rounds
             ]),
             Self::Some(rounds) => {
                 let rounds = rounds.into_iter()
-                    .map(|round| round.bound(final_round));
+                    .map(|round| round.bound(final_round, names));
                 BoundedRounds::try_new(rounds)
             }
         }
@@ -1,5 +1,6 @@
 use super::Round;
 
+#[derive(Clone)]
 pub struct SomeRounds
 {
     rounds: Vec<Round>
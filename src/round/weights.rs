@@ -0,0 +1,97 @@
+use crate::rational::Rational;
+
+/// Separates the weights of each round, like "1,1,2".
+pub const WEIGHT_SEPARATOR: char = ',';
+/// The weight of a round that isn't given one.
+pub const DEFAULT_WEIGHT: Rational = Rational::ONE;
+
+/// What the points in each round are multiplied by.
+#[derive(Clone, Default, Debug)]
+pub struct Weights
+{
+    weights: Vec<Option<Rational>>
+}
+
+impl Weights
+{
+    pub fn new(weights: impl IntoIterator<Item = Option<Rational>>) -> Self
+    {
+        Self {
+            weights: weights.into_iter().collect()
+        }
+    }
+
+    pub fn weight(&self, round: usize) -> Rational
+    {
+        self.given(round).unwrap_or(DEFAULT_WEIGHT)
+    }
+
+    /// The weight of the round, if it was given one.
+    pub fn given(&self, round: usize) -> Option<Rational>
+    {
+        self.weights.get(round).copied().flatten()
+    }
+
+    /// Parses the weight of one round, where nothing means the default weight, or gives it back if it isn't valid.
+    pub fn parse_weight(weight: &str) -> Result<Option<Rational>, Box<str>>
+    {
+        let weight = weight.trim();
+        if weight.is_empty()
+        {
+            return Ok(None)
+        }
+        match weight.parse::<Rational>()
+        {
+            Ok(weight) if weight >= Rational::ZERO => Ok(Some(weight)),
+            _ => Err(weight.into())
+        }
+    }
+
+    /// Parses the weight of each round, or gives the weight that isn't valid.
+    pub fn parse(weights: &str) -> Result<Self, Box<str>>
+    {
+        weights.split(WEIGHT_SEPARATOR)
+            .map(Self::parse_weight)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::new)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, ExitCode, InvalidRead, InvalidSyntax}, tests::TempFile};
+
+    #[test]
+    fn weights() -> Result<(), Error>
+    {
+        let file = TempFile::new("weights", "@weights: , , 2\nQuizzbuzz: 5 3! 2\nKate's Bush: 3 5 2+1!\nBlank: 2 3 -\n");
+
+        assert_eq!(file.scores(&["--output", "csv"])?, "place,team,1,2,3,total,climb\r\n1,Kate's Bush,3,5,12,20,1\r\n2,Quizzbuzz,5,6,4,15,-1\r\n3,Blank,2,3,,5,0\r\n");
+        assert_eq!(file.scores(&["--weights", "2", "--output", "csv"])?, "place,team,1,2,3,total,climb\r\n1,Quizzbuzz,10,6,2,18,0\r\n2,Kate's Bush,6,5,6,17,0\r\n3,Blank,4,3,,7,0\r\n");
+
+        // The other outputs show the points before and after, like the table does
+        assert!(file.scores(&["--output", "markdown", "--table"])?.contains("| 1. | Kate's Bush | 3 | 5 | 3\\*4=12 | 20 | ↑1 |"));
+        assert!(file.scores(&["--output", "html", "--table"])?.contains("<td class=\"points\">5</td><td class=\"points\">3*2=6</td><td class=\"points\">2*2=4</td>"));
+
+        let json = file.scores(&["--output", "json"])?;
+        assert!(json.contains("\"weights\": [1, 1, 2],"));
+        assert!(json.contains("\"rounds\": [3, 5, 3], \"expressions\": [null, null, \"2+1\"], \"played\": 3, \"tie_break\": null, \"effective\": [3, 5, 12], \"counted\": [true, true, true]}"));
+        assert!(json.contains("\"rounds\": [2, 3, null], \"expressions\": [null, null, null], \"played\": 2, \"tie_break\": null, \"effective\": [2, 3, null], \"counted\": [true, true, true]}"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn one_joker_per_team()
+    {
+        let file = TempFile::new("jokers", "Quizzbuzz: 5! 3! 2!\n");
+
+        let error = file.scores(&[]).unwrap_err();
+        assert!(matches!(error, Error::InvalidRead { error: InvalidRead::InvalidSyntax { ref errors }, .. } if matches!(errors[..], [
+            InvalidSyntax::DuplicateJoker { row: 1, round: 2, first_round: 1, ref col, .. },
+            InvalidSyntax::DuplicateJoker { row: 1, round: 3, first_round: 1, .. }
+        ] if *col == (15..16))));
+        assert_eq!(error.exit_code(), ExitCode::InvalidSyntax);
+    }
+}
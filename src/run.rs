@@ -1,35 +1,117 @@
-use std::{borrow::Cow, path::Path};
+use crate::{command::{Command, Enter}, error::{Error, InvalidRead, Warning}, output::Output, record::{Records, Source}, reveal::Reveal, score::Scores, scoring::Scoring, watch::Watch};
 
-use crate::{error::Error, record::Records, round::Rounds, score::Scores};
+/// The warnings in each file that has any.
+pub type Warnings<'a> = Vec<(&'a Source, Box<[Warning]>)>;
 
 pub struct Run
 {
-    rounds: Rounds,
-    file: Cow<'static, Path>,
-    rev: bool
+    scoring: Scoring,
+    sources: Box<[Source]>,
+    output: Output,
+    strict: bool,
+    watch: bool,
+    reveal: Option<Reveal>,
+    command: Option<Command>
 }
 
 impl Run
 {
-    pub fn new(rounds: Rounds, file: Cow<'static, Path>, rev: bool) -> Result<Self, Error>
+    pub fn new(scoring: Scoring, sources: Box<[Source]>, output: Output, strict: bool, watch: bool, reveal: Option<Reveal>, command: Option<Command>) -> Result<Self, Error>
     {
         Ok(Self {
-            rounds,
-            file,
-            rev
+            scoring,
+            sources,
+            output,
+            strict,
+            watch,
+            reveal,
+            command
         })
     }
-    fn records(&self) -> Result<Records, Error>
+    /// Reads every file and puts them together, with the warnings of each file checked on their own.
+    pub fn records(&self) -> Result<(Records, Warnings<'_>), Error>
     {
-        Records::read(self).map_err(|e| e.at(self.file.clone()))
+        let mut files = Vec::with_capacity(self.sources.len());
+        let mut warnings = Vec::new();
+        for source in &self.sources
+        {
+            let (records, file_warnings) = self.read(source)?;
+            if !file_warnings.is_empty()
+            {
+                warnings.push((source, file_warnings));
+            }
+            files.push((source, records));
+        }
+
+        Ok((Records::merge(files)?, warnings))
+    }
+    /// Reads one file, and checks it for warnings.
+    pub fn read(&self, source: &Source) -> Result<(Records, Box<[Warning]>), Error>
+    {
+        let records = Records::read(source).map_err(|e| e.at(source.clone()))?;
+
+        let warnings = records.lint();
+        if self.strict && !warnings.is_empty()
+        {
+            return Err(InvalidRead::Strict {
+                warnings
+            }.at(source.clone()))
+        }
+
+        Ok((records, warnings))
+    }
+    /// Reads the file again, without using up the run, so that it can be done more than once.
+    pub fn rescore(&self) -> Result<(Scores, Warnings<'_>), Error>
+    {
+        let (records, warnings) = self.records()?;
+        Ok((Scores::new(records, self.scoring.clone(), self.output)?, warnings))
     }
     pub fn scores(self) -> Result<Scores, Error>
     {
-        let records = self.records()?;
-        Ok(Scores::new(records, self.rounds, self.rev)?)
+        let (scores, warnings) = self.rescore()?;
+        for (source, warnings) in warnings
+        {
+            eprintln!("{warnings}\n", warnings = InvalidRead::warnings(&warnings, source));
+        }
+        Ok(scores)
+    }
+    pub fn present(self) -> Result<(), Error>
+    {
+        match &self.command
+        {
+            Some(Command::Enter) => return Enter::new(&self).start(std::io::stdin().lock(), std::io::stdout().lock()),
+            Some(Command::AddRound(add_round)) => return add_round.start(&self, std::io::stdin().lock(), std::io::stdout().lock()),
+            Some(Command::Season(season)) => return season.start(&self),
+            None => ()
+        }
+        if self.watch
+        {
+            Watch::new(self).start()
+        }
+        let reveal = self.reveal;
+        let scores = self.scores()?;
+        match reveal
+        {
+            Some(reveal) => reveal.present(&scores, std::io::stdin().lock(), std::io::stdout().lock())?,
+            None => scores.present()
+        }
+        Ok(())
+    }
+    /// The file that commands write to, which is the only one when there is a command.
+    pub fn source(&self) -> &Source
+    {
+        &self.sources[0]
+    }
+    pub fn sources(&self) -> &[Source]
+    {
+        &self.sources
+    }
+    pub fn scoring(&self) -> &Scoring
+    {
+        &self.scoring
     }
-    pub fn file_path(&self) -> &Path
+    pub fn output(&self) -> Output
     {
-        &self.file
+        self.output
     }
-}
\ No newline at end of file
+}
@@ -1,47 +1,85 @@
 use core::{cmp::Ordering, fmt::Display};
+use std::borrow::Cow;
 
-use crate::{error::InsufficientData, record::Records, round::{BoundedRounds, Rounds}, style};
+use crate::{error::Error, output::{Chart, Delimited, Format, Html, Json, Markdown, Notation, Output, Table}, rational::Rational, record::{Entry, Records}, round::{BoundedRounds, Count, RoundNames, Weights}, scoring::{Ranking, Scoring, TieBreak}, style};
 
 #[derive(PartialEq, Clone)]
 pub struct Score
 {
     pub team: Box<str>,
-    pub points: f64,
-    pub place: usize,
-    pub climb: isize,
-    pub uid: usize
+    pub points: Rational,
+    pub place: Rational,
+    pub climb: Rational,
+    pub uid: usize,
+    pub rounds: Box<[Entry]>,
+    /// The points for each round, after the weight of the round and the team's joker are applied.
+    pub effective: Box<[Option<Rational>]>,
+    /// Whether each round counts towards the total, or was discarded.
+    pub counted: Box<[bool]>,
+    /// The tie-break that decided the place, if the team had the same points as the team next to it.
+    pub tie_break: Option<TieBreak>,
+    /// The place of the team after each of the rounds, ending with `place`. Only filled in for the chart.
+    pub history: Box<[Rational]>
 }
-impl PartialOrd for Score
+impl Score
 {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.points.partial_cmp(&other.points)
+    /// How many of the rounds the team participated in.
+    pub fn played(&self) -> usize
+    {
+        self.rounds.iter()
+            .filter_map(Entry::points)
+            .count()
+    }
+
+    /// The points of a round, followed by what they were multiplied by and what that gave, like "5*2=10", if the weight of the round or the team's joker changed them.
+    pub fn weighted(&self, round: usize, weight: Rational, notation: Notation) -> Option<String>
+    {
+        let entry = &self.rounds[round];
+        let points = notation.format(entry.points()?);
+        match (entry.factor(weight), self.effective[round])
         {
-            None | Some(Ordering::Equal) => Some(
-                self.climb.cmp(&other.climb)
-                    .then(self.team.cmp(&other.team))
-            ),
-            cmp => cmp
+            (Ok(factor), Some(effective)) if factor != Rational::ONE => Some(format!("{points}*{factor}={effective}", effective = notation.format(effective))),
+            _ => Some(points)
+        }
+    }
+
+    pub fn line(&self, notation: Notation) -> ScoreLine<'_>
+    {
+        ScoreLine {
+            score: self,
+            notation
         }
     }
 }
-impl Display for Score
+
+impl PartialOrd for Score
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.points.cmp(&other.points)
+            .then(self.climb.cmp(&other.climb))
+            .then(self.team.cmp(&other.team))
+        )
+    }
+}
+
+/// The line of a team in the leaderboard, with the points written in the given notation.
+pub struct ScoreLine<'a>
+{
+    score: &'a Score,
+    notation: Notation
+}
+
+impl Display for ScoreLine<'_>
 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        let Self { team, points, place, climb, uid: _ } = self;
-        let points = if *points == -0.0
-        {
-            0.0
-        }
-        else
-        {
-            *points
-        };
-        write!(f, "{place} {team} {points}{climb}",
+        let Score { team, points, place, climb, uid: _, rounds: _, effective: _, counted: _, tie_break, history: _ } = self.score;
+        write!(f, "{place} {team} {points}{climb}{tie_break}",
             place = style::place(*place),
             team = style::team(format!("{team}:", team = &**team).as_str()),
-            points = style::points(format!("{points}").as_str()),
-            climb = style::climb(*climb)
+            points = style::points(self.notation.format(*points)),
+            climb = style::climb(*climb),
+            tie_break = style::tie_break(*tie_break)
         )
     }
 }
@@ -49,47 +87,141 @@ impl Display for Score
 pub struct Scores
 {
     scores: Vec<Score>,
-    rev: bool
+    columns: Box<[usize]>,
+    round_names: RoundNames,
+    tie_breaks: Box<[TieBreak]>,
+    ranking: Ranking,
+    weights: Box<[Rational]>,
+    output: Output
 }
 
-impl Scores
+impl Display for Scores
 {
-    pub fn present(mut self)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
     {
-        self.sort();
-    
-        if self.rev
+        match self.output.format
         {
-            for score in self.scores.into_iter().rev()
+            Format::Text => if self.output.chart
             {
-                println!("{score}");
+                Chart::new(self).fmt(f)
             }
-        }
-        else
-        {
-            for score in self.scores
+            else if self.output.table
             {
-                println!("{score}");
+                Table::new(self).fmt(f)
             }
+            else
+            {
+                for score in self.ordered()
+                {
+                    writeln!(f, "{score}", score = score.line(self.output.notation))?;
+                }
+                Ok(())
+            },
+            Format::Json => Json::new(self).fmt(f),
+            Format::Csv => Delimited::new(self, ',', self.output.long).fmt(f),
+            Format::Tsv => Delimited::new(self, '\t', self.output.long).fmt(f),
+            Format::Markdown => Markdown::new(self, self.output.table).fmt(f),
+            Format::Html => Html::new(self, self.output.table).fmt(f)
         }
     }
+}
+
+impl Scores
+{
+    pub fn present(self)
+    {
+        print!("{self}");
+    }
+
+    pub fn ordered(&self) -> Vec<&Score>
+    {
+        let mut scores = self.scores.iter()
+            .collect::<Vec<_>>();
+        if self.output.rev
+        {
+            scores.reverse();
+        }
+        scores
+    }
+
+    pub fn columns(&self) -> &[usize]
+    {
+        &self.columns
+    }
+
+    pub fn round_names(&self) -> &RoundNames
+    {
+        &self.round_names
+    }
+
+    pub fn round_label(&self, round: usize) -> Cow<'_, str>
+    {
+        self.round_names.label(round)
+    }
+
+    pub fn notation(&self) -> Notation
+    {
+        self.output.notation
+    }
+
+    /// The weight of each of the columns.
+    pub fn weights(&self) -> &[Rational]
+    {
+        &self.weights
+    }
 
     fn sort(&mut self)
     {
-        self.scores.sort_by(|a, b| b.partial_cmp(a).unwrap_or(Ordering::Equal));
+        // The most points anyone got in each round, for counting round wins
+        let tops = (0..self.columns.len())
+            .map(|round| self.scores.iter()
+                .filter_map(|score| score.effective[round])
+                .max()
+            ).collect::<Vec<_>>();
+        // The columns in the order the rounds were played, so that the final round is the last one, even if it was chosen first
+        let mut chronological = (0..self.columns.len())
+            .collect::<Vec<_>>();
+        chronological.sort_by_key(|&i| self.columns[i]);
+
+        let tie_breaks = &self.tie_breaks;
+        self.scores.sort_by(|a, b| match b.points.cmp(&a.points)
+        {
+            Ordering::Equal => TieBreak::decide(tie_breaks, b, a, &tops, &chronological)
+                .map(|(cmp, _)| cmp)
+                .unwrap_or_else(|| b.partial_cmp(a).unwrap_or(Ordering::Equal)),
+            cmp => cmp
+        });
 
         let mut j = 0;
-        let mut prev = None;
+        let mut prev: Option<&Score> = None;
+        let mut decided = Vec::with_capacity(self.scores.len());
 
-        for (i, score) in self.scores.iter_mut()
+        for (i, score) in self.scores.iter()
             .enumerate()
         {
-            if Some(score.points) != prev
+            let tie_break = prev.filter(|prev| prev.points == score.points)
+                .map(|prev| TieBreak::decide(tie_breaks, prev, score, &tops, &chronological).map(|(_, tie_break)| tie_break));
+            if !matches!(tie_break, Some(None))
             {
                 j = i + 1;
-                prev = Some(score.points);
             }
-            score.place = j
+            decided.push((j, tie_break.flatten()));
+            prev = Some(score);
+        }
+
+        let places = self.ranking.places(decided.chunk_by(|(a, _), (b, _)| a == b).map(<[_]>::len));
+
+        // The team placed ahead by the tie-break shows it too
+        let aheads = decided.iter()
+            .skip(1)
+            .map(|&(_, tie_break)| tie_break)
+            .chain(core::iter::once(None));
+        for ((score, place), (&(_, tie_break), ahead)) in self.scores.iter_mut()
+            .zip(places)
+            .zip(decided.iter().zip(aheads))
+        {
+            score.place = place;
+            score.tie_break = tie_break.or(ahead);
         }
     }
 
@@ -101,53 +233,158 @@ impl Scores
             {
                 if other.uid == score.uid
                 {
-                    score.climb = crate::checked_signed_diff(other.place, score.place).unwrap_or(0)
+                    score.climb = other.place.checked_sub(score.place)
+                        .expect("places are counted in teams, so the climb between two of them is never too large");
                 }
             }
         }
     }
 
-    pub fn new(records: Records, rounds: Rounds, rev: bool) -> Result<Scores, InsufficientData>
+    pub fn new(records: Records, scoring: Scoring, output: Output) -> Result<Scores, Error>
     {
-        fn scores_no_climb(records: Records, rounds: BoundedRounds, rev: bool) -> Scores
+        fn scores_no_climb(records: Records, rounds: BoundedRounds, count: Count, tie_breaks: Box<[TieBreak]>, ranking: Ranking, weights: &Weights, output: Output) -> Result<Scores, Error>
         {
+            let columns = rounds.indices()
+                .collect::<Box<[_]>>();
+            let weights = columns.iter()
+                .map(|&round| weights.weight(round))
+                .collect::<Box<[_]>>();
+            let round_names = records.round_names().clone();
             let mut scores = Scores {
                 scores: records.into_iter()
                     .enumerate()
-                    .map(move |(uid, record)| Score {
-                        team: record.team,
-                        points: rounds.iter()
-                            .flat_map(|&round| round.index(&record.points))
-                            .flatten()
-                            .copied()
-                            .sum(),
-                        climb: 0,
-                        place: 0,
-                        uid
-                    }).collect::<Vec<_>>(),
-                rev
+                    .map(|(uid, record)| {
+                        let too_large = || Error::PointsTooLarge {
+                            team: record.team.clone()
+                        };
+                        let rounds = columns.iter()
+                            .map(|&round| record.points.get(round).cloned().unwrap_or(Entry::Absent))
+                            .collect::<Box<[_]>>();
+                        let effective = rounds.iter()
+                            .zip(weights.iter())
+                            .map(|(entry, &weight)| entry.effective(weight))
+                            .collect::<Result<Box<[_]>, _>>()
+                            .map_err(|_| too_large())?;
+                        let counted = count.counted(&effective);
+                        let points = effective.iter()
+                            .zip(counted.iter())
+                            .filter(|&(_, &counted)| counted)
+                            .filter_map(|(&points, _)| points)
+                            .try_fold(Rational::ZERO, Rational::checked_add)
+                            .ok_or_else(too_large)?;
+                        Ok(Score {
+                            team: record.team,
+                            points,
+                            climb: Rational::ZERO,
+                            place: Rational::ZERO,
+                            uid,
+                            rounds,
+                            effective,
+                            counted,
+                            tie_break: None,
+                            history: [].into()
+                        })
+                    }).collect::<Result<Vec<_>, Error>>()?,
+                columns,
+                round_names,
+                tie_breaks,
+                ranking,
+                weights,
+                output
             };
             scores.sort();
-            scores
+            Ok(scores)
         }
 
-        let rounds = rounds.bound(records.final_round())?;
-        
-        let prev_scores = rounds.clone()
-            .undo()
+        let Scoring { rounds, count, tie_breaks, ranking, weights, since } = scoring;
+        let rounds = rounds.bound(records.final_round(), records.round_names())?;
+        let tie_breaks = tie_breaks.unwrap_or_else(|| records.tie_breaks().into());
+        let weights = weights.unwrap_or_else(|| records.weights().clone());
+
+        // The climb is since the round before, unless it's asked to be since some other rounds
+        let prev_rounds = match since
+        {
+            Some(since) => Some(since.bound(records.final_round(), records.round_names())?),
+            None => rounds.clone().undo()
+        };
+        let prev_scores = prev_rounds
             .map(|prev_rounds| scores_no_climb(
                 records.clone(),
                 prev_rounds,
-                rev
-            ));
+                count,
+                tie_breaks.clone(),
+                ranking,
+                &weights,
+                output
+            ))
+            .transpose()?;
 
-        let mut scores = scores_no_climb(records, rounds, rev);
+        // The places after each of the rounds before the last one, by team
+        let mut history = vec![Vec::new(); if output.chart {records.iter().len()} else {0}];
+        let mut prefix = rounds.clone().undo().filter(|_| output.chart);
+        while let Some(rounds) = prefix
+        {
+            for score in scores_no_climb(records.clone(), rounds.clone(), count, tie_breaks.clone(), ranking, &weights, output)?.scores
+            {
+                history[score.uid].push(score.place);
+            }
+            prefix = rounds.undo();
+        }
+
+        let mut scores = scores_no_climb(records, rounds, count, tie_breaks, ranking, &weights, output)?;
 
         if let Some(prev_scores) = prev_scores
         {
-            scores.compared_to(prev_scores)
+            scores.compared_to(prev_scores);
+            scores.sort();
+        }
+
+        for (score, history) in scores.scores.iter_mut()
+            .filter_map(|score| history.get_mut(score.uid).map(|history| (score, core::mem::take(history))))
+        {
+            score.history = history.into_iter()
+                .rev()
+                .chain(core::iter::once(score.place))
+                .collect();
         }
 
         Ok(scores)
     }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::{Error, InsufficientData}, tests::TempFile};
+
+    #[test]
+    fn since() -> Result<(), Error>
+    {
+        let file = TempFile::new("since", "@rounds: Music, Halftime, Geography, Final\nQuizzbuzz: 1 1 10 10\nKate's Bush: 5 5 1 1\nBlank: 3 3 3 3\n");
+        let scores = |args: &[&str]| file.scores(&[args, &["--output", "csv"]].concat());
+
+        let header = "place,team,Music,Halftime,Geography,Final,total,climb\r\n";
+        assert_eq!(scores(&[])?, format!("{header}1,Quizzbuzz,1,1,10,10,22,0\r\n2,Blank,3,3,3,3,12,1\r\n2,Kate's Bush,5,5,1,1,12,0\r\n"));
+        assert_eq!(scores(&["--since", "Halftime"])?, format!("{header}1,Quizzbuzz,1,1,10,10,22,2\r\n2,Blank,3,3,3,3,12,0\r\n2,Kate's Bush,5,5,1,1,12,-1\r\n"));
+        assert_eq!(scores(&["--since", "2"])?, scores(&["--since", "1..2"])?);
+        // Only the final round, compared to only the round before it
+        assert_eq!(scores(&["Final", "--since", "Geography..Geography"])?, "place,team,Final,total,climb\r\n1,Quizzbuzz,10,10,0\r\n2,Blank,3,3,0\r\n3,Kate's Bush,1,1,0\r\n");
+
+        let error = scores(&["--since", "Overtime"]).unwrap_err();
+        assert!(matches!(error, Error::InsufficientData { error: InsufficientData::UnknownRound { .. } }));
+
+        Ok(())
+    }
+
+    #[test]
+    fn too_large()
+    {
+        // Each of the points can be written exactly, but not their total
+        for (name, points) in [("too-large", "99999999999999999999999999999999999999"), ("too-large-expression", "1e30*1e8")]
+        {
+            let file = TempFile::new(name, &format!("Quizzbuzz: {points} {points}\nKate's Bush: 1 2\n"));
+            let error = file.scores(&[]).unwrap_err();
+            assert!(matches!(error, Error::PointsTooLarge { ref team } if &**team == "Quizzbuzz"));
+        }
+    }
 }
\ No newline at end of file
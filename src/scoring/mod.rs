@@ -0,0 +1,7 @@
+moddef::moddef!(
+    flat(pub) mod {
+        ranking,
+        scoring,
+        tie_break
+    }
+);
@@ -0,0 +1,89 @@
+use core::fmt::Display;
+
+use crate::rational::Rational;
+
+/// How places are numbered when teams share a place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Ranking
+{
+    /// Teams that share a place leave a gap after them, like 1, 2, 2, 4.
+    Standard,
+    /// Teams that share a place don't leave a gap after them, like 1, 2, 2, 3.
+    Dense,
+    /// Every team gets its own place, even if they have the same points, like 1, 2, 3, 4.
+    Ordinal,
+    /// Teams that share a place get the average of the places they take up, like 1, 2.5, 2.5, 4.
+    Fractional
+}
+
+impl Ranking
+{
+    pub const VARIANT_COUNT: usize = 4; //core::mem::variant_count::<Ranking>(); TODO use this instead when stable
+    pub const VARIANTS: [Ranking; Self::VARIANT_COUNT] = [Ranking::Standard, Ranking::Dense, Ranking::Ordinal, Ranking::Fractional];
+
+    pub const fn name(self) -> &'static str
+    {
+        match self
+        {
+            Self::Standard => "standard",
+            Self::Dense => "dense",
+            Self::Ordinal => "ordinal",
+            Self::Fractional => "fractional"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        Self::VARIANTS.into_iter()
+            .find(|ranking| ranking.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// The place of each team, in order, given how many teams share each place.
+    pub fn places(self, shared: impl IntoIterator<Item = usize>) -> Vec<Rational>
+    {
+        let mut places = Vec::new();
+        for (group, len) in shared.into_iter()
+            .enumerate()
+        {
+            let start = places.len() as i128 + 1;
+            let len = len as i128;
+            places.extend((0..len).map(|i| match self
+            {
+                Self::Standard => Rational::integer(start),
+                Self::Dense => Rational::integer(group as i128 + 1),
+                Self::Ordinal => Rational::integer(start + i),
+                Self::Fractional => Rational::new(2*start + len - 1, 2)
+                    .expect("places are counted in teams, so they are never too large")
+            }));
+        }
+        places
+    }
+}
+
+impl Display for Ranking
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.name().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn rankings() -> Result<(), Error>
+    {
+        let file = TempFile::new("rankings", "Quizzbuzz: 5 5\nKate's Bush: 3 5\nBlank: 3 5\nTable \\#7: 1 1\n");
+        let scores = |ranking: &str| file.scores(&["--ranking", ranking, "--output", "csv"]);
+
+        assert_eq!(scores("standard")?, "place,team,1,2,total,climb\r\n1,Quizzbuzz,5,5,10,0\r\n2,Kate's Bush,3,5,8,0\r\n2,Blank,3,5,8,0\r\n4,Table #7,1,1,2,0\r\n");
+        assert_eq!(scores("dense")?, "place,team,1,2,total,climb\r\n1,Quizzbuzz,5,5,10,0\r\n2,Kate's Bush,3,5,8,0\r\n2,Blank,3,5,8,0\r\n3,Table #7,1,1,2,0\r\n");
+        assert_eq!(scores("ordinal")?, "place,team,1,2,total,climb\r\n1,Quizzbuzz,5,5,10,0\r\n2,Kate's Bush,3,5,8,0\r\n3,Blank,3,5,8,0\r\n4,Table #7,1,1,2,0\r\n");
+        assert_eq!(scores("fractional")?, "place,team,1,2,total,climb\r\n1,Quizzbuzz,5,5,10,0\r\n2.5,Kate's Bush,3,5,8,0\r\n2.5,Blank,3,5,8,0\r\n4,Table #7,1,1,2,0\r\n");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use crate::round::{Count, Rounds, Weights};
+
+use super::{Ranking, TieBreak};
+
+/// How the points are added up into a leaderboard.
+#[derive(Clone)]
+pub struct Scoring
+{
+    pub rounds: Rounds,
+    pub count: Count,
+    /// Overrides the tie-breaks given in the poengsum-file.
+    pub tie_breaks: Option<Box<[TieBreak]>>,
+    pub ranking: Ranking,
+    /// Overrides the weights given in the poengsum-file.
+    pub weights: Option<Weights>,
+    /// The rounds the climb is compared to, instead of the round before.
+    pub since: Option<Rounds>
+}
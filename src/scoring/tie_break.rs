@@ -0,0 +1,131 @@
+use core::{cmp::Ordering, fmt::Display};
+
+use crate::{rational::Rational, score::Score};
+
+/// Separates the rules in a chain of tie-breaks, like "last-round,countback".
+pub const TIE_BREAK_SEPARATOR: char = ',';
+
+/// A rule for deciding which of two teams with the same points gets the better place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TieBreak
+{
+    /// The most points in the final round.
+    LastRound,
+    /// The most points in any one round.
+    BestRound,
+    /// The most rounds where nobody got more points.
+    Wins,
+    /// The most points in the final round, then the round before it, and so on.
+    Countback,
+    /// The teams share the place, and no rules after this one are tried.
+    Shared
+}
+
+impl TieBreak
+{
+    pub const VARIANT_COUNT: usize = 5; //core::mem::variant_count::<TieBreak>(); TODO use this instead when stable
+    pub const VARIANTS: [TieBreak; Self::VARIANT_COUNT] = [TieBreak::LastRound, TieBreak::BestRound, TieBreak::Wins, TieBreak::Countback, TieBreak::Shared];
+
+    pub const fn name(self) -> &'static str
+    {
+        match self
+        {
+            Self::LastRound => "last-round",
+            Self::BestRound => "best-round",
+            Self::Wins => "wins",
+            Self::Countback => "countback",
+            Self::Shared => "shared"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self>
+    {
+        Self::VARIANTS.into_iter()
+            .find(|tie_break| tie_break.name().eq_ignore_ascii_case(name.trim()))
+    }
+
+    /// Parses a chain of rules, or gives the name that isn't one.
+    pub fn parse_chain(chain: &str) -> Result<Box<[Self]>, Box<str>>
+    {
+        chain.split(TIE_BREAK_SEPARATOR)
+            .map(|name| Self::from_name(name).ok_or_else(|| name.trim().into()))
+            .collect()
+    }
+
+    /// Which of the teams is ahead by this rule, where `tops` is the most points anyone got in each round, and `chronological` is the columns in the order they were played, whatever order they were chosen in.
+    pub fn compare(self, a: &Score, b: &Score, tops: &[Option<Rational>], chronological: &[usize]) -> Ordering
+    {
+        // Not playing a round is the same as getting nothing in it
+        let points = |points: &Option<Rational>| points.unwrap_or(Rational::ZERO);
+        let round = |round: usize| points(&a.effective[round]).cmp(&points(&b.effective[round]));
+        match self
+        {
+            Self::LastRound => chronological.last()
+                .map_or(Ordering::Equal, |&last| round(last)),
+            Self::BestRound => {
+                let best = |score: &Score| score.effective.iter()
+                    .map(points)
+                    .max()
+                    .unwrap_or(Rational::ZERO);
+                best(a).cmp(&best(b))
+            },
+            Self::Wins => {
+                let wins = |score: &Score| score.effective.iter()
+                    .zip(tops)
+                    .filter(|(points, top)| points.is_some() && points == top)
+                    .count();
+                wins(a).cmp(&wins(b))
+            },
+            Self::Countback => chronological.iter()
+                .rev()
+                .map(|&i| round(i))
+                .find(|cmp| cmp.is_ne())
+                .unwrap_or(Ordering::Equal),
+            Self::Shared => Ordering::Equal
+        }
+    }
+
+    /// Goes through the rules in order until one of them tells the teams apart, and gives which rule it was.
+    pub fn decide(chain: &[Self], a: &Score, b: &Score, tops: &[Option<Rational>], chronological: &[usize]) -> Option<(Ordering, Self)>
+    {
+        chain.iter()
+            .take_while(|&&tie_break| tie_break != Self::Shared)
+            .map(|&tie_break| (tie_break.compare(a, b, tops, chronological), tie_break))
+            .find(|(cmp, _)| cmp.is_ne())
+    }
+}
+
+impl Display for TieBreak
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result
+    {
+        self.name().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use crate::{error::Error, tests::TempFile};
+
+    #[test]
+    fn tie_breaks() -> Result<(), Error>
+    {
+        let file = TempFile::new("tie-breaks", "@tie-break: last-round\nQuizzbuzz: 5 3 2\nKate's Bush: 3 5 2\nBlank: 2 3 5\nTable \\#7: 1 1 1\n");
+
+        // Quizzbuzz and Kate's Bush are still tied after the final round
+        assert_eq!(file.scores(&["--output", "csv"])?, "place,team,1,2,3,total,climb\r\n1,Blank,2,3,5,10,2\r\n2,Quizzbuzz,5,3,2,10,0\r\n2,Kate's Bush,3,5,2,10,-1\r\n4,Table #7,1,1,1,3,0\r\n");
+        assert_eq!(file.scores(&["--tie-break", "last-round,countback", "--output", "csv"])?, "place,team,1,2,3,total,climb\r\n1,Blank,2,3,5,10,2\r\n2,Kate's Bush,3,5,2,10,-1\r\n3,Quizzbuzz,5,3,2,10,-1\r\n4,Table #7,1,1,1,3,0\r\n");
+        assert_eq!(file.scores(&["--tie-break", "wins,shared,countback", "--output", "csv"])?, "place,team,1,2,3,total,climb\r\n1,Blank,2,3,5,10,2\r\n1,Quizzbuzz,5,3,2,10,0\r\n1,Kate's Bush,3,5,2,10,0\r\n4,Table #7,1,1,1,3,0\r\n");
+
+        let json = file.scores(&["--tie-break", "best-round,countback", "--output", "json"])?;
+        assert!(json.contains("{\"team\": \"Quizzbuzz\", \"uid\": 0, \"place\": 3, \"points\": 10, \"climb\": -1, \"rounds\": [5, 3, 2], \"expressions\": [null, null, null], \"played\": 3, \"tie_break\": \"countback\", \"effective\": [5, 3, 2], \"counted\": [true, true, true]}"));
+        assert!(json.contains("{\"team\": \"Table #7\", \"uid\": 3, \"place\": 4, \"points\": 3, \"climb\": 0, \"rounds\": [1, 1, 1], \"expressions\": [null, null, null], \"played\": 3, \"tie_break\": null, \"effective\": [1, 1, 1], \"counted\": [true, true, true]}"));
+
+        // The final round is the last one played, not the last one chosen
+        assert!(file.scores(&["3", "1", "--output", "csv"])?.starts_with("place,team,3,1,total,climb\r\n1,Blank,5,2,7,"));
+        assert!(file.scores(&["3", "1", "--tie-break", "countback", "--output", "csv"])?.starts_with("place,team,3,1,total,climb\r\n1,Blank,5,2,7,"));
+
+        Ok(())
+    }
+}
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 
 use ansiterm::{ANSIString, Color, Style};
 
-use crate::{error::Severity, terminal};
+use crate::{error::Severity, rational::Rational, scoring::TieBreak, terminal};
 
 const CLIMB_UP_COLOR: Color = Color::Green;
 const CLIMB_DOWN_COLOR: Color = Color::Red;
@@ -11,19 +11,36 @@ const CLIMB_DOWN_COLOR: Color = Color::Red;
 const PLACE_COLOR: Color = Color::White;
 const TEAM_COLOR: Color = Color::White;
 const POINTS_COLOR: Color = Color::White;
+const MISSING_COLOR: Color = Color::Fixed(8);
+const TIE_BREAK_COLOR: Color = Color::Fixed(8);
+const TEAM_COLORS: [Color; 12] = [
+    Color::Fixed(196),
+    Color::Fixed(46),
+    Color::Fixed(33),
+    Color::Fixed(226),
+    Color::Fixed(201),
+    Color::Fixed(51),
+    Color::Fixed(208),
+    Color::Fixed(141),
+    Color::Fixed(118),
+    Color::Fixed(213),
+    Color::Fixed(39),
+    Color::Fixed(180)
+];
 
 const EXE_COLOR: Color = Color::Blue;
 const ARG_COLOR: Color = Color::Cyan;
 
 const INFO_COLOR: Color = Color::Fixed(8);
 const ERROR_COLOR: Color = Color::Fixed(1);
-//const WARNING_COLOR: Color = Color::Yellow;
+const WARNING_COLOR: Color = Color::Yellow;
 const LINE_COLOR: Color = Color::Fixed(8);
 const TEAM_LINE_COLOR: Color = Color::Fixed(221);
 const POINTS_LINE_COLOR: Color = Color::White;
+const COMMENT_LINE_COLOR: Color = Color::Fixed(8);
 const ROW_COLOR: Color = Color::Fixed(240);
 
-pub fn place(place: usize) -> ANSIString<'static>
+pub fn place(place: Rational) -> ANSIString<'static>
 {
     PLACE_COLOR.paint(format!("{place}."))
 }
@@ -40,6 +57,17 @@ where
     POINTS_COLOR.paint(input)
 }
 
+/// Points that don't count towards the total.
+pub fn discarded(points: String) -> ANSIString<'static>
+{
+    MISSING_COLOR.paint(format!("({points})"))
+}
+
+pub fn missing() -> ANSIString<'static>
+{
+    MISSING_COLOR.paint(if supports_unicode::supports_unicode() {"·"} else {"-"})
+}
+
 pub fn header<'a, T>(input: T) -> ANSIString<'a>
 where
     T: Into<Cow<'a, str>>
@@ -47,16 +75,61 @@ where
     Style::new().bold().paint(input)
 }
 
-pub fn climb(climb: isize) -> ANSIString<'static>
+fn climb_arrow(climb: Rational, supports_unicode: bool) -> Option<(char, Color)>
+{
+    match climb.cmp(&Rational::ZERO)
+    {
+        Ordering::Greater => Some((if supports_unicode {'↑'} else {'^'}, CLIMB_UP_COLOR)),
+        Ordering::Less => Some((if supports_unicode {'↓'} else {'v'}, CLIMB_DOWN_COLOR)),
+        Ordering::Equal => None
+    }
+}
+
+pub fn climb(climb: Rational) -> ANSIString<'static>
+{
+    match climb_arrow(climb, supports_unicode::supports_unicode())
+    {
+        Some((arrow, color)) => color.paint(format!(" {}{}", arrow, climb.abs())),
+        None => "".into()
+    }
+}
+
+/// The climb arrow without any terminal colors, for documents that are not shown in the terminal.
+pub fn climb_text(climb: Rational) -> String
+{
+    match climb_arrow(climb, true)
+    {
+        Some((arrow, _)) => format!("{}{}", arrow, climb.abs()),
+        None => String::new()
+    }
+}
+
+/// The same team always gets the same colour, picked by its line in the poengsum-file.
+fn team_color(uid: usize) -> Color
+{
+    TEAM_COLORS[uid % TEAM_COLORS.len()]
+}
+
+/// A part of a team's line in the chart.
+pub fn chart_line(c: char, uid: usize) -> ANSIString<'static>
+{
+    team_color(uid).paint(c.to_string())
+}
+
+pub fn chart_team<'a, T>(input: T, uid: usize) -> ANSIString<'a>
+where
+    T: Into<Cow<'a, str>>
+{
+    team_color(uid).paint(input)
+}
+
+pub fn tie_break(tie_break: Option<TieBreak>) -> ANSIString<'static>
 {
-    let supports_unicode = supports_unicode::supports_unicode();
-    let (arrow, color) = match climb.cmp(&0)
+    match tie_break
     {
-        Ordering::Greater => (if supports_unicode {'↑'} else {'^'}, CLIMB_UP_COLOR),
-        Ordering::Equal => return "".into(),
-        Ordering::Less => (if supports_unicode {'↓'} else {'v'}, CLIMB_DOWN_COLOR)
-    };
-    color.paint(format!(" {}{}", arrow, climb.unsigned_abs()))
+        Some(tie_break) => TIE_BREAK_COLOR.paint(format!(" ({tie_break})")),
+        None => "".into()
+    }
 }
 
 pub fn exe<'a, T>(input: T) -> ANSIString<'a>
@@ -83,16 +156,29 @@ where
 {
     ERROR_COLOR.paint(input)
 }
+pub fn error_banner<'a, T>(input: T) -> ANSIString<'a>
+where
+    T: Into<Cow<'a, str>>
+{
+    ERROR_COLOR.bold().reverse().paint(input)
+}
 
 fn severity_color(severity: Severity) -> Color
 {
     match severity
     {
-        //Severity::Warning => WARNING_COLOR,
+        Severity::Warning => WARNING_COLOR,
         Severity::Error => ERROR_COLOR
     }
 }
 
+pub fn severity<'a, T>(input: T, severity: Severity) -> ANSIString<'a>
+where
+    T: Into<Cow<'a, str>>
+{
+    severity_color(severity).paint(input)
+}
+
 pub fn syntax_arrow(offset: usize, severity: Severity) -> ANSIString<'static>
 {
     severity_color(severity).paint(format!("{offset}v", offset = " ".repeat(offset)))
@@ -136,6 +222,12 @@ where
 {
     POINTS_LINE_COLOR.paint(input)
 }
+pub fn comment_line<'a, T>(input: T) -> ANSIString<'a>
+where
+    T: Into<Cow<'a, str>>
+{
+    COMMENT_LINE_COLOR.paint(input)
+}
 pub fn row<'a, T>(input: T) -> ANSIString<'a>
 where
     T: Into<Cow<'a, str>>
@@ -1,5 +1,10 @@
 #![allow(unused)]
 
+use core::fmt::Display;
+use std::io::{BufRead, Write};
+
+use crate::{error::{Error, InvalidIO, InvalidRead}, record::Source};
+
 fn is_kitty() -> bool
 {
     std::env::var("KITTY_WINDOW_ID").is_ok()
@@ -25,4 +30,41 @@ fn is_iterm() -> bool
 pub fn supports_colored_underline() -> bool
 {
     false //is_kitty() || is_vte() || is_mintty() || is_iterm()
-}
\ No newline at end of file
+}
+
+/// Clears the screen and moves the cursor to the top left corner.
+pub const CLEAR: &str = "\x1b[2J\x1b[H";
+
+pub fn width() -> Option<usize>
+{
+    terminal_size::terminal_size()
+        .map(|(terminal_size::Width(width), _)| width as usize)
+        .or_else(|| std::env::var("COLUMNS").ok()?.parse().ok())
+}
+
+/// Writes to the terminal, and shows it right away.
+///
+/// Like with print!, there's nothing better to do than panic if the terminal is gone.
+pub fn show(output: &mut impl Write, text: impl Display)
+{
+    write!(output, "{text}")
+        .and_then(|()| output.flush())
+        .expect("failed writing to output");
+}
+
+/// Reads the next line typed in, which is the given row of the input, or nothing if there is no more input.
+pub fn read_line(input: &mut impl BufRead, row: usize) -> Result<Option<String>, Error>
+{
+    let mut line = String::new();
+    match input.read_line(&mut line)
+    {
+        Ok(0) => Ok(None),
+        Ok(_) => Ok(Some(line)),
+        Err(io_error) => Err(InvalidRead::InvalidIO {
+            io_error,
+            error: InvalidIO::Read {
+                row
+            }
+        }.at(Source::Stdin))
+    }
+}
@@ -0,0 +1,87 @@
+use std::{io::{IsTerminal, Write}, time::{Duration, SystemTime}};
+
+use crate::{error::InvalidRead, run::Run, style, terminal};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Redraws the leaderboard every time the poengsum-file is saved.
+pub struct Watch
+{
+    run: Run,
+    last: Option<String>
+}
+
+impl Watch
+{
+    pub fn new(run: Run) -> Self
+    {
+        Self {
+            run,
+            last: None
+        }
+    }
+
+    /// Some file systems only keep the time in whole seconds, so the size is checked too.
+    fn modified(&self) -> Vec<Option<(SystemTime, u64)>>
+    {
+        self.run.sources()
+            .iter()
+            .map(|source| {
+                let metadata = std::fs::metadata(source.path()?).ok()?;
+                Some((metadata.modified().ok()?, metadata.len()))
+            }).collect()
+    }
+
+    fn redraw(&mut self)
+    {
+        let mut frame = String::new();
+
+        match self.run.rescore()
+        {
+            Ok((scores, warnings)) => {
+                for (source, warnings) in warnings
+                {
+                    frame += &format!("{warnings}\n\n", warnings = InvalidRead::warnings(&warnings, source));
+                }
+                let leaderboard = scores.to_string();
+                frame += &leaderboard;
+                self.last = Some(leaderboard);
+            },
+            // Keep showing the last leaderboard that worked, so the screen isn't blank while someone is typing
+            Err(error) => {
+                if let Some(last) = &self.last
+                {
+                    frame += last;
+                    frame += "\n";
+                }
+                frame += &format!("{banner}\n\n{error}\n", banner = style::error_banner("The leaderboard could not be updated:"));
+            }
+        }
+
+        let mut stdout = std::io::stdout().lock();
+        if stdout.is_terminal()
+        {
+            let _ = write!(stdout, "{clear}", clear = terminal::CLEAR);
+        }
+        let _ = write!(stdout, "{frame}");
+        let _ = stdout.flush();
+    }
+
+    pub fn start(mut self) -> !
+    {
+        let mut modified = self.modified();
+        self.redraw();
+
+        loop
+        {
+            std::thread::sleep(POLL_INTERVAL);
+
+            let now_modified = self.modified();
+            if now_modified != modified
+            {
+                modified = now_modified;
+                self.redraw();
+            }
+        }
+    }
+}
@@ -0,0 +1,152 @@
+use std::{io::Write, path::PathBuf, process::{Command, Stdio}};
+
+const POENGSUM: &str = env!("CARGO_BIN_EXE_poengsum");
+
+/// A folder to run in, with the poengsum-file if there is one, that is removed when dropped, so that the tests don't leave it behind.
+struct Dir
+{
+    path: PathBuf
+}
+
+impl Dir
+{
+    fn new(name: &str, file: Option<&str>) -> Self
+    {
+        let path = std::env::temp_dir().join(format!("poengsum-exit-codes-{name}-{pid}", pid = std::process::id()));
+        std::fs::create_dir_all(&path).unwrap();
+
+        if let Some(file) = file
+        {
+            std::fs::write(path.join("poengsum.txt"), file).unwrap();
+        }
+
+        Self {
+            path
+        }
+    }
+}
+
+impl Drop for Dir
+{
+    fn drop(&mut self)
+    {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
+
+fn exit_code(name: &str, file: Option<&str>, args: &[&str]) -> i32
+{
+    let dir = Dir::new(name, file);
+    let output = Command::new(POENGSUM)
+        .args(args)
+        .current_dir(&dir.path)
+        .output()
+        .unwrap();
+
+    output.status.code().unwrap()
+}
+
+fn exit_code_with_input(name: &str, input: &str, args: &[&str]) -> i32
+{
+    let dir = Dir::new(name, None);
+    let mut child = Command::new(POENGSUM)
+        .args(args)
+        .current_dir(&dir.path)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+
+    // Fails if the program exits because of its arguments before reading any of it
+    let _ = child.stdin.take().unwrap().write_all(input.as_bytes());
+
+    child.wait().unwrap().code().unwrap()
+}
+
+const FILE: &str = "Quizzbuzz: 3 5 15\nKate's Bush: 42.5 21 42.5\n";
+
+#[test]
+fn success()
+{
+    assert_eq!(exit_code("success", Some(FILE), &[]), 0);
+    assert_eq!(exit_code("success-rounds", Some(FILE), &["2", "1..3"]), 0);
+    assert_eq!(exit_code_with_input("success-stdin", FILE, &["--file", "-"]), 0);
+    assert_eq!(exit_code_with_input("success-season-stdin", FILE, &["season", "-"]), 0);
+}
+
+#[test]
+fn show_help()
+{
+    assert_eq!(exit_code("show-help", Some(FILE), &["--help"]), 0);
+    assert_eq!(exit_code("show-help-file", Some(FILE), &["--help", "--file", "poengsum.txt"]), 0);
+    assert_eq!(exit_code("show-help-flag", Some(FILE), &["--help", "--file"]), 1);
+}
+
+#[test]
+fn invalid_arg()
+{
+    assert_eq!(exit_code("round-zero", Some(FILE), &["0"]), 2);
+    assert_eq!(exit_code("one-dot", Some(FILE), &["1.3"]), 2);
+    assert_eq!(exit_code("unknown-round", Some(FILE), &["x"]), 2);
+    assert_eq!(exit_code("nonexistent-flag", Some(FILE), &["--nonexistent"]), 2);
+    assert_eq!(exit_code_with_input("stdin-watch", FILE, &["--file", "-", "--watch"]), 2);
+    assert_eq!(exit_code("watch-reveal", Some(FILE), &["--watch", "--reveal"]), 2);
+    assert_eq!(exit_code("podium-watch", Some(FILE), &["--podium", "--watch"]), 2);
+    assert_eq!(exit_code_with_input("stdin-enter", FILE, &["enter", "--file", "-"]), 2);
+    assert_eq!(exit_code("season-aggregate", Some(FILE), &["season", "--aggregate", "average"]), 2);
+    assert_eq!(exit_code("tie-break", Some(FILE), &["--tie-break", "coin-toss"]), 2);
+    assert_eq!(exit_code("ranking", Some(FILE), &["--ranking", "olympic"]), 2);
+    assert_eq!(exit_code("weights", Some(FILE), &["--weights", "1,double"]), 2);
+    assert_eq!(exit_code("count", Some(FILE), &["--count", "best-0"]), 2);
+    assert_eq!(exit_code("decimals", Some(FILE), &["--decimals", "-1"]), 2);
+    assert_eq!(exit_code("since", Some(FILE), &["--since", "0"]), 2);
+    assert_eq!(exit_code("files-enter", Some(FILE), &["--file", "poengsum.txt", "--file", "poengsum.txt", "enter"]), 2);
+}
+
+#[test]
+fn expected_arg()
+{
+    assert_eq!(exit_code("expected-arg", Some(FILE), &["--file"]), 3);
+}
+
+#[test]
+fn invalid_io()
+{
+    assert_eq!(exit_code("invalid-io", None, &[]), 4);
+}
+
+#[test]
+fn invalid_syntax()
+{
+    assert_eq!(exit_code("missing-colon", Some("Quizzbuzz 3 5 15\n"), &[]), 5);
+    assert_eq!(exit_code("no-teams", Some(""), &[]), 5);
+    assert_eq!(exit_code("cannot-parse-points", Some("Quizzbuzz: 3 five 15\n"), &[]), 5);
+    assert_eq!(exit_code("invalid-weight", Some("@weights: 1, -2\nQuizzbuzz: 3 5\n"), &[]), 5);
+    assert_eq!(exit_code("unknown-tie-break", Some("@tie-break: countback, coin-toss\nQuizzbuzz: 3\n"), &[]), 5);
+    assert_eq!(exit_code_with_input("cannot-parse-points-stdin", "Quizzbuzz: 3 five 15\n", &["--file", "-"]), 5);
+    assert_eq!(exit_code("warning", Some("Quizzbuzz: 3 5 15\nQuizzbuzz: 1 2 3\n"), &[]), 0);
+    assert_eq!(exit_code("strict", Some("Quizzbuzz: 3 5 15\nQuizzbuzz: 1 2 3\n"), &["--strict"]), 5);
+}
+
+#[test]
+fn insufficient_data()
+{
+    assert_eq!(exit_code("round-not-yet", Some(FILE), &["4"]), 6);
+    assert_eq!(exit_code("no-rounds-yet", Some("Quizzbuzz:\n"), &[]), 6);
+}
+
+#[test]
+fn invalid_entry()
+{
+    assert_eq!(exit_code("unknown-team", Some(FILE), &["add-round", "Blank=3"]), 8);
+    assert_eq!(exit_code("missing-separator", Some(FILE), &["add-round", "Quizzbuzz"]), 8);
+    assert_eq!(exit_code("new-team", Some(FILE), &["add-round", "Blank=3", "--new"]), 0);
+}
+
+#[test]
+fn points_too_large()
+{
+    assert_eq!(exit_code("points-too-large", Some("Quizzbuzz: 99999999999999999999999999999999999999 99999999999999999999999999999999999999\n"), &[]), 9);
+    assert_eq!(exit_code("points-too-large-expression", Some("Quizzbuzz: 1e30*1e8 1e30*1e8\n"), &[]), 9);
+}